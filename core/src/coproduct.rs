@@ -73,7 +73,12 @@
 
 use hlist::{HCons, HNil};
 use indices::{Here, There};
-use traits::{Func, Poly, ToMut, ToRef};
+use traits::{Func, FuncMut, FuncRef, Poly, PolyMut, PolyRef, ToMut, ToRef, TypeNames};
+
+use std::any;
+#[cfg(feature = "std")]
+use std::error::Error;
+use std::fmt;
 
 /// Enum type representing a Coproduct. Think of this as a Result, but capable
 /// of supporting any arbitrary number of types instead of just 2.
@@ -452,6 +457,141 @@ impl<Head, Tail> Coproduct<Head, Tail> {
         CoproductEmbedder::embed(self)
     }
 
+    /// Widen the Coproduct by adding the variants of another Coproduct type
+    /// after its own.
+    ///
+    /// The value is kept as is; only the type grows. Unlike [`embed`], this
+    /// never needs any index inference, so it also works when the same type
+    /// appears more than once.
+    ///
+    /// [`embed`]: #method.embed
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate frunk;
+    /// # fn main() {
+    /// type I32Bool = Coprod!(i32, bool);
+    ///
+    /// let co = I32Bool::inject(true);
+    /// let extended = co.extend_right::<Coprod!(f32, i32)>();
+    /// assert_eq!(extended, <Coprod!(i32, bool, f32, i32)>::inject(true));
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub fn extend_right<Rhs>(self) -> <Self as CoproductAppend<Rhs>>::Output
+    where
+        Self: CoproductAppend<Rhs>,
+    {
+        CoproductAppend::extend_right(self)
+    }
+
+    /// Widen the Coproduct by adding the variants of another Coproduct type
+    /// before its own.
+    ///
+    /// This is the counterpart of [`extend_right`].
+    ///
+    /// [`extend_right`]: #method.extend_right
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate frunk;
+    /// # fn main() {
+    /// use frunk::Coproduct::{Inl, Inr};
+    ///
+    /// type I32Bool = Coprod!(i32, bool);
+    ///
+    /// let co = I32Bool::inject(3);
+    /// let extended = co.extend_left::<Coprod!(f32, i32)>();
+    /// let expected: Coprod!(f32, i32, i32, bool) = Inr(Inr(Inl(3)));
+    /// assert_eq!(extended, expected);
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub fn extend_left<Lhs>(self) -> <Lhs as CoproductAppend<Self>>::Output
+    where
+        Lhs: CoproductAppend<Self>,
+    {
+        Lhs::extend_left(self)
+    }
+
+    /// Move the Coproduct into the union of its type with another Coproduct
+    /// type, in which each type only appears once.
+    ///
+    /// The union `Out` is not computed: the compiler cannot tell types
+    /// apart, only match them, so `Out` must be given. It is checked to be
+    /// the variants of `Self`, followed by variants that are each reached by
+    /// a variant of `Other`, and to hold each type of `Other` in a single
+    /// place. Duplicates within `Other` are fine, and are collapsed, like
+    /// with [`embed`]. Junk and duplicate variants in `Out` are only rejected
+    /// when `Indices` is left as `_`, for the compiler to infer.
+    ///
+    /// Values of `Other` are moved into the union with [`union_left`].
+    ///
+    /// [`embed`]: #method.embed
+    /// [`union_left`]: #method.union_left
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate frunk;
+    /// # fn main() {
+    /// use frunk::Coproduct::{Inl, Inr};
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct IoError;
+    /// #[derive(Debug, PartialEq)]
+    /// struct ParseError;
+    /// #[derive(Debug, PartialEq)]
+    /// struct DbError;
+    ///
+    /// type ConfigError = Coprod!(IoError, ParseError);
+    /// type StoreError = Coprod!(DbError, IoError, IoError);
+    /// type AppError = Coprod!(IoError, ParseError, DbError);
+    ///
+    /// let e1 = ConfigError::inject(ParseError);
+    /// let app = e1.union_right::<StoreError, AppError, _>();
+    /// assert_eq!(app, AppError::inject(ParseError));
+    ///
+    /// let e2: StoreError = Inr(Inl(IoError));
+    /// let app = e2.union_left::<ConfigError, AppError, _>();
+    /// assert_eq!(app, AppError::inject(IoError));
+    /// # }
+    /// ```
+    ///
+    /// A variant that comes from neither operand does not compile:
+    ///
+    /// ```compile_fail
+    /// # #[macro_use] extern crate frunk;
+    /// # fn main() {
+    /// let u = <Coprod!(i32, bool)>::inject(1)
+    ///     .union_right::<Coprod!(bool), Coprod!(i32, bool, f64), _>();
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub fn union_right<Other, Out, Indices>(self) -> Out
+    where
+        Self: CoproductUnion<Other, Out, Indices>,
+    {
+        CoproductUnion::union_right(self)
+    }
+
+    /// Move the Coproduct into the union of another Coproduct type with its
+    /// own type, in which each type only appears once.
+    ///
+    /// This is the counterpart of [`union_right`], where `self` is the
+    /// second operand of the union.
+    ///
+    /// [`union_right`]: #method.union_right
+    #[inline(always)]
+    pub fn union_left<Lhs, Out, Indices>(self) -> Out
+    where
+        Lhs: CoproductUnion<Self, Out, Indices>,
+    {
+        Lhs::union_left(self)
+    }
+
     /// Borrow each variant of the Coproduct.
     ///
     /// # Example
@@ -507,8 +647,12 @@ impl<Head, Tail> Coproduct<Head, Tail> {
     /// * An `hlist![]` of closures (one for each type, in order).
     /// * A single closure (for a Coproduct that is homogenous).
     /// * A single [`Poly`].
+    /// * A single [`PolyRef`], for folders that read some context.
+    /// * A single [`PolyMut`], for folders that need some state.
     ///
     /// [`Poly`]: ../traits/struct.Poly.html
+    /// [`PolyRef`]: ../traits/struct.PolyRef.html
+    /// [`PolyMut`]: ../traits/struct.PolyMut.html
     ///
     /// # Example
     ///
@@ -571,6 +715,195 @@ impl<Head, Tail> Coproduct<Head, Tail> {
     {
         CoproductFoldable::fold(self, folder)
     }
+
+    /// Apply a function to the value held by the Coproduct, keeping the
+    /// result in a Coproduct of the same shape.
+    ///
+    /// Whereas [`fold`] collapses every variant into one output type, `map`
+    /// transforms each variant into its own new type. The same types as for
+    /// [`fold`] are supported for the `Mapper` argument:
+    ///
+    /// * An `hlist![]` of closures (one for each type, in order).
+    /// * A single closure (for a Coproduct that is homogenous).
+    /// * A single [`Poly`].
+    /// * A single [`PolyRef`], for mappers that read some context.
+    /// * A single [`PolyMut`], for mappers that need some state.
+    ///
+    /// [`fold`]: #method.fold
+    /// [`Poly`]: ../traits/struct.Poly.html
+    /// [`PolyRef`]: ../traits/struct.PolyRef.html
+    /// [`PolyMut`]: ../traits/struct.PolyMut.html
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate frunk;
+    /// # fn main() {
+    /// use frunk::Coproduct;
+    ///
+    /// type I32String = Coprod!(i32, String);
+    /// type F64Usize = Coprod!(f64, usize);
+    ///
+    /// let co1 = I32String::inject(3);
+    /// let co2 = I32String::inject("hello".to_string());
+    ///
+    /// let mapper = hlist![|i: i32| f64::from(i) / 2.0, |s: String| s.len()];
+    ///
+    /// let mapped: F64Usize = co1.map(mapper);
+    /// assert_eq!(mapped, F64Usize::inject(1.5));
+    ///
+    /// // Map over references to leave the original untouched
+    /// let mapped = co2.to_ref().map(hlist![|&i: &i32| i > 0, |s: &String| s.is_empty()]);
+    /// assert_eq!(mapped, <Coprod!(bool, bool)>::Inr(Coproduct::Inl(false)));
+    /// assert_eq!(co2, I32String::inject("hello".to_string()));
+    /// # }
+    /// ```
+    ///
+    /// Using a polymorphic function:
+    ///
+    /// ```
+    /// # #[macro_use] extern crate frunk;
+    /// # fn main() {
+    /// use frunk::{Func, Poly};
+    ///
+    /// struct Wrap;
+    ///
+    /// impl<T> Func<T> for Wrap {
+    ///     type Output = Option<T>;
+    ///     fn call(t: T) -> Self::Output {
+    ///         Some(t)
+    ///     }
+    /// }
+    ///
+    /// let co = <Coprod!(i32, bool)>::inject(true);
+    /// let mapped = co.map(Poly(Wrap));
+    /// assert_eq!(mapped, <Coprod!(Option<i32>, Option<bool>)>::inject(Some(true)));
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub fn map<Mapper>(self, mapper: Mapper) -> <Self as CoproductMappable<Mapper>>::Output
+    where
+        Self: CoproductMappable<Mapper>,
+    {
+        CoproductMappable::map(self, mapper)
+    }
+
+    /// Returns the names of the variant types of this Coproduct, as given by
+    /// `std::any::type_name`.
+    ///
+    /// The result is an HList of `&'static str`, which can be turned into a
+    /// `Vec` or an array.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate frunk;
+    /// # fn main() {
+    /// type I32StrBool = Coprod!(i32, &'static str, bool);
+    ///
+    /// let names: [&str; 3] = I32StrBool::type_names().into();
+    /// assert_eq!(names, ["i32", "&str", "bool"]);
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub fn type_names() -> <Self as TypeNames>::Names
+    where
+        Self: TypeNames,
+    {
+        <Self as TypeNames>::type_names()
+    }
+
+    /// Returns the position of the variant held by the Coproduct, starting
+    /// from 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate frunk;
+    /// # fn main() {
+    /// type I32StrBool = Coprod!(i32, &'static str, bool);
+    ///
+    /// assert_eq!(I32StrBool::inject(1).index(), 0);
+    /// assert_eq!(I32StrBool::inject(true).index(), 2);
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub fn index(&self) -> usize
+    where
+        Self: CoproductVariants,
+    {
+        CoproductVariants::index(self)
+    }
+
+    /// Returns the name of the type of the variant held by the Coproduct, as
+    /// given by `std::any::type_name`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate frunk;
+    /// # fn main() {
+    /// type I32StrBool = Coprod!(i32, &'static str, bool);
+    ///
+    /// assert_eq!(I32StrBool::inject("hi").variant_type_name(), "&str");
+    /// assert_eq!(I32StrBool::inject(false).variant_type_name(), "bool");
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub fn variant_type_name(&self) -> &'static str
+    where
+        Self: CoproductVariants,
+    {
+        CoproductVariants::variant_type_name(self)
+    }
+
+    /// Returns the number of variants of this Coproduct type.
+    ///
+    /// The same number is available in const contexts as
+    /// [`CoproductVariants::VARIANT_COUNT`].
+    ///
+    /// [`CoproductVariants::VARIANT_COUNT`]: trait.CoproductVariants.html#associatedconstant.VARIANT_COUNT
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate frunk;
+    /// # fn main() {
+    /// type I32StrBool = Coprod!(i32, &'static str, bool);
+    ///
+    /// assert_eq!(I32StrBool::variant_count(), 3);
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub fn variant_count() -> usize
+    where
+        Self: CoproductVariants,
+    {
+        <Self as CoproductVariants>::VARIANT_COUNT
+    }
+}
+
+impl<T, E> Coproduct<T, Coproduct<E, CNil>> {
+    /// Convert a Coproduct of two types into a `Result`, with the first type
+    /// as the `Ok` variant.
+    ///
+    /// This is the same as using `Into`, but doesn't need an annotation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate frunk;
+    /// # fn main() {
+    /// type I32Str = Coprod!(i32, &'static str);
+    ///
+    /// assert_eq!(I32Str::inject(3).into_result(), Ok(3));
+    /// assert_eq!(I32Str::inject("nope").into_result(), Err("nope"));
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub fn into_result(self) -> Result<T, E> {
+        self.into()
+    }
 }
 
 /// Trait for instantiating a coproduct from an element
@@ -745,6 +1078,34 @@ where
     }
 }
 
+impl<P, R, CH, CTail> CoproductFoldable<PolyMut<P>, R> for Coproduct<CH, CTail>
+where
+    P: FuncMut<CH, Output = R>,
+    CTail: CoproductFoldable<PolyMut<P>, R>,
+{
+    fn fold(self, mut f: PolyMut<P>) -> R {
+        use self::Coproduct::*;
+        match self {
+            Inl(r) => f.0.call_mut(r),
+            Inr(rest) => rest.fold(f),
+        }
+    }
+}
+
+impl<P, R, CH, CTail> CoproductFoldable<PolyRef<P>, R> for Coproduct<CH, CTail>
+where
+    P: FuncRef<CH, Output = R>,
+    CTail: CoproductFoldable<PolyRef<P>, R>,
+{
+    fn fold(self, f: PolyRef<P>) -> R {
+        use self::Coproduct::*;
+        match self {
+            Inl(r) => f.0.call_ref(r),
+            Inr(rest) => rest.fold(f),
+        }
+    }
+}
+
 impl<F, R, FTail, CH, CTail> CoproductFoldable<HCons<F, FTail>, R> for Coproduct<CH, CTail>
 where
     F: FnOnce(CH) -> R,
@@ -768,6 +1129,115 @@ impl<F, R> CoproductFoldable<F, R> for CNil {
     }
 }
 
+/// Trait for mapping over a coproduct's variants.
+///
+/// This trait is part of the implementation of the inherent method
+/// [`Coproduct::map`]. Please see that method for more information.
+///
+/// You only need to import this trait when working with generic
+/// Coproducts or Mappers of unknown type. If the type of everything is known,
+/// then `co.map(mapper)` should "just work" even without the trait.
+///
+/// [`Coproduct::map`]: enum.Coproduct.html#method.map
+pub trait CoproductMappable<Mapper> {
+    type Output;
+
+    /// Use functions to map each variant of a coproduct.
+    ///
+    /// Please see the [inherent method] for more information.
+    ///
+    /// The only difference between that inherent method and this
+    /// trait method is the location of the type parameters.
+    /// (here, they are on the trait rather than the method)
+    ///
+    /// [inherent method]: enum.Coproduct.html#method.map
+    fn map(self, mapper: Mapper) -> Self::Output;
+}
+
+impl<F, R, CH, CTail> CoproductMappable<F> for Coproduct<CH, CTail>
+where
+    F: FnOnce(CH) -> R,
+    CTail: CoproductMappable<F>,
+{
+    type Output = Coproduct<R, <CTail as CoproductMappable<F>>::Output>;
+
+    fn map(self, f: F) -> Self::Output {
+        match self {
+            Coproduct::Inl(h) => Coproduct::Inl(f(h)),
+            Coproduct::Inr(rest) => Coproduct::Inr(rest.map(f)),
+        }
+    }
+}
+
+impl<F, R, FTail, CH, CTail> CoproductMappable<HCons<F, FTail>> for Coproduct<CH, CTail>
+where
+    F: FnOnce(CH) -> R,
+    CTail: CoproductMappable<FTail>,
+{
+    type Output = Coproduct<R, <CTail as CoproductMappable<FTail>>::Output>;
+
+    fn map(self, mapper: HCons<F, FTail>) -> Self::Output {
+        match self {
+            Coproduct::Inl(h) => Coproduct::Inl((mapper.head)(h)),
+            Coproduct::Inr(rest) => Coproduct::Inr(rest.map(mapper.tail)),
+        }
+    }
+}
+
+impl<P, R, CH, CTail> CoproductMappable<Poly<P>> for Coproduct<CH, CTail>
+where
+    P: Func<CH, Output = R>,
+    CTail: CoproductMappable<Poly<P>>,
+{
+    type Output = Coproduct<R, <CTail as CoproductMappable<Poly<P>>>::Output>;
+
+    fn map(self, poly: Poly<P>) -> Self::Output {
+        match self {
+            Coproduct::Inl(h) => Coproduct::Inl(P::call(h)),
+            Coproduct::Inr(rest) => Coproduct::Inr(rest.map(poly)),
+        }
+    }
+}
+
+impl<P, R, CH, CTail> CoproductMappable<PolyMut<P>> for Coproduct<CH, CTail>
+where
+    P: FuncMut<CH, Output = R>,
+    CTail: CoproductMappable<PolyMut<P>>,
+{
+    type Output = Coproduct<R, <CTail as CoproductMappable<PolyMut<P>>>::Output>;
+
+    fn map(self, mut poly: PolyMut<P>) -> Self::Output {
+        match self {
+            Coproduct::Inl(h) => Coproduct::Inl(poly.0.call_mut(h)),
+            Coproduct::Inr(rest) => Coproduct::Inr(rest.map(poly)),
+        }
+    }
+}
+
+impl<P, R, CH, CTail> CoproductMappable<PolyRef<P>> for Coproduct<CH, CTail>
+where
+    P: FuncRef<CH, Output = R>,
+    CTail: CoproductMappable<PolyRef<P>>,
+{
+    type Output = Coproduct<R, <CTail as CoproductMappable<PolyRef<P>>>::Output>;
+
+    fn map(self, poly: PolyRef<P>) -> Self::Output {
+        match self {
+            Coproduct::Inl(h) => Coproduct::Inl(poly.0.call_ref(h)),
+            Coproduct::Inr(rest) => Coproduct::Inr(rest.map(poly)),
+        }
+    }
+}
+
+/// This is literally impossible; CNil is not instantiable
+impl<F> CoproductMappable<F> for CNil {
+    type Output = CNil;
+
+    fn map(self, _: F) -> Self::Output {
+        match self {}
+    }
+}
+
 impl<'a, CH: 'a, CTail> ToRef<'a> for Coproduct<CH, CTail>
 where
     CTail: ToRef<'a>,
@@ -814,6 +1284,219 @@ impl<'a> ToMut<'a> for CNil {
     }
 }
 
+impl<CH, CTail> TypeNames for Coproduct<CH, CTail>
+where
+    CTail: TypeNames,
+{
+    type Names = HCons<&'static str, <CTail as TypeNames>::Names>;
+
+    fn type_names() -> Self::Names {
+        HCons {
+            head: any::type_name::<CH>(),
+            tail: CTail::type_names(),
+        }
+    }
+}
+
+impl TypeNames for CNil {
+    type Names = HNil;
+
+    fn type_names() -> Self::Names {
+        HNil
+    }
+}
+
+/// Trait for inspecting which variant of a Coproduct is active, without
+/// folding it.
+///
+/// This trait is part of the implementation of the inherent methods
+/// [`Coproduct::index`], [`Coproduct::variant_type_name`] and
+/// [`Coproduct::variant_count`]. Please see those methods for more information.
+///
+/// You only need to import this trait when working with generic
+/// Coproducts of unknown type, or to use `VARIANT_COUNT` in a const context.
+///
+/// [`Coproduct::index`]: enum.Coproduct.html#method.index
+/// [`Coproduct::variant_type_name`]: enum.Coproduct.html#method.variant_type_name
+/// [`Coproduct::variant_count`]: enum.Coproduct.html#method.variant_count
+pub trait CoproductVariants {
+    /// The number of variants.
+    const VARIANT_COUNT: usize;
+
+    /// Returns the position of the active variant.
+    ///
+    /// Please see the [inherent method] for more information.
+    ///
+    /// [inherent method]: enum.Coproduct.html#method.index
+    fn index(&self) -> usize;
+
+    /// Returns the type name of the active variant.
+    ///
+    /// Please see the [inherent method] for more information.
+    ///
+    /// [inherent method]: enum.Coproduct.html#method.variant_type_name
+    fn variant_type_name(&self) -> &'static str;
+}
+
+impl<CH, CTail> CoproductVariants for Coproduct<CH, CTail>
+where
+    CTail: CoproductVariants,
+{
+    const VARIANT_COUNT: usize = 1 + CTail::VARIANT_COUNT;
+
+    fn index(&self) -> usize {
+        match *self {
+            Coproduct::Inl(_) => 0,
+            Coproduct::Inr(ref rest) => 1 + rest.index(),
+        }
+    }
+
+    fn variant_type_name(&self) -> &'static str {
+        match *self {
+            Coproduct::Inl(_) => any::type_name::<CH>(),
+            Coproduct::Inr(ref rest) => rest.variant_type_name(),
+        }
+    }
+}
+
+impl CoproductVariants for CNil {
+    const VARIANT_COUNT: usize = 0;
+
+    fn index(&self) -> usize {
+        match *self {}
+    }
+
+    fn variant_type_name(&self) -> &'static str {
+        match *self {}
+    }
+}
+
+/// Formats the value held by the Coproduct, whichever variant it is.
+///
+/// ```
+/// # #[macro_use] extern crate frunk; fn main() {
+/// type I32StrBool = Coprod!(i32, &'static str, bool);
+///
+/// assert_eq!(I32StrBool::inject(1).to_string(), "1");
+/// assert_eq!(I32StrBool::inject("hello").to_string(), "hello");
+/// assert_eq!(I32StrBool::inject(true).to_string(), "true");
+/// # }
+/// ```
+impl<H, T> fmt::Display for Coproduct<H, T>
+where
+    H: fmt::Display,
+    T: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Coproduct::Inl(ref h) => h.fmt(f),
+            Coproduct::Inr(ref t) => t.fmt(f),
+        }
+    }
+}
+
+impl fmt::Display for CNil {
+    fn fmt(&self, _: &mut fmt::Formatter) -> fmt::Result {
+        match *self {}
+    }
+}
+
+/// A Coproduct of errors is an error itself.
+///
+/// Like its `Display` implementation, `source` is forwarded to the error held
+/// by the Coproduct, so that the Coproduct is transparent in error reports.
+///
+/// ```
+/// # #[macro_use] extern crate frunk; fn main() {
+/// use std::error::Error;
+/// use std::num::{ParseFloatError, ParseIntError};
+///
+/// type ParseError = Coprod!(ParseIntError, ParseFloatError);
+///
+/// let err = ParseError::inject("x".parse::<i32>().unwrap_err());
+/// let boxed: Box<dyn Error> = Box::new(err);
+/// assert_eq!(boxed.to_string(), "invalid digit found in string");
+/// assert!(boxed.source().is_none());
+/// # }
+/// ```
+#[cfg(feature = "std")]
+impl<H, T> Error for Coproduct<H, T>
+where
+    H: Error,
+    T: Error,
+{
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            Coproduct::Inl(ref h) => h.source(),
+            Coproduct::Inr(ref t) => t.source(),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for CNil {}
+
+/// Converts a `Result` into a Coproduct of its `Ok` and `Err` types.
+///
+/// ```
+/// # #[macro_use] extern crate frunk; fn main() {
+/// let r: Result<i32, String> = Err("nope".to_string());
+/// let co: Coprod!(i32, String) = r.into();
+/// assert_eq!(co.get::<String, _>().map(String::as_str), Some("nope"));
+///
+/// let r: Result<i32, String> = co.into();
+/// assert!(r.is_err());
+/// # }
+/// ```
+impl<T, E> From<Result<T, E>> for Coproduct<T, Coproduct<E, CNil>> {
+    fn from(r: Result<T, E>) -> Self {
+        match r {
+            Ok(t) => Coproduct::Inl(t),
+            Err(e) => Coproduct::Inr(Coproduct::Inl(e)),
+        }
+    }
+}
+
+impl<T, E> From<Coproduct<T, Coproduct<E, CNil>>> for Result<T, E> {
+    fn from(co: Coproduct<T, Coproduct<E, CNil>>) -> Self {
+        match co {
+            Coproduct::Inl(t) => Ok(t),
+            Coproduct::Inr(Coproduct::Inl(e)) => Err(e),
+            Coproduct::Inr(Coproduct::Inr(nil)) => match nil {},
+        }
+    }
+}
+
+/// Converts an `Option` into a Coproduct, with `()` standing for `None`.
+///
+/// ```
+/// # #[macro_use] extern crate frunk; fn main() {
+/// let co: Coprod!(i32, ()) = Some(3).into();
+/// assert_eq!(co.get::<i32, _>(), Some(&3));
+///
+/// let o: Option<i32> = <Coprod!(i32, ())>::inject(()).into();
+/// assert_eq!(o, None);
+/// # }
+/// ```
+impl<T> From<Option<T>> for Coproduct<T, Coproduct<(), CNil>> {
+    fn from(o: Option<T>) -> Self {
+        match o {
+            Some(t) => Coproduct::Inl(t),
+            None => Coproduct::Inr(Coproduct::Inl(())),
+        }
+    }
+}
+
+impl<T> From<Coproduct<T, Coproduct<(), CNil>>> for Option<T> {
+    fn from(co: Coproduct<T, Coproduct<(), CNil>>) -> Self {
+        match co {
+            Coproduct::Inl(t) => Some(t),
+            Coproduct::Inr(Coproduct::Inl(())) => None,
+            Coproduct::Inr(Coproduct::Inr(nil)) => match nil {},
+        }
+    }
+}
+
 /// Trait for extracting a value from a coproduct in an exhaustive way.
 ///
 /// This trait is part of the implementation of the inherent method
@@ -974,6 +1657,276 @@ where
     }
 }
 
+/// Trait for concatenating two coproduct types.
+///
+/// `Output` is a Coproduct with the variants of `Self` followed by those of
+/// `Rhs`, and values of either type can be moved into it.
+///
+/// This trait is part of the implementation of the inherent methods
+/// [`Coproduct::extend_right`] and [`Coproduct::extend_left`]. Please see
+/// those methods for more information.
+///
+/// You only need to import this trait when working with generic
+/// Coproducts of unknown type, or to name the concatenated type.
+///
+/// ```
+/// # #[macro_use] extern crate frunk;
+/// # fn main() {
+/// use frunk::coproduct::CoproductAppend;
+///
+/// type I32Bool = Coprod!(i32, bool);
+/// type I32BoolF32 = <I32Bool as CoproductAppend<Coprod!(f32)>>::Output;
+///
+/// let co = I32BoolF32::inject(1.5f32);
+/// assert_eq!(co, <Coprod!(i32, bool, f32)>::inject(1.5f32));
+/// # }
+/// ```
+///
+/// [`Coproduct::extend_right`]: enum.Coproduct.html#method.extend_right
+/// [`Coproduct::extend_left`]: enum.Coproduct.html#method.extend_left
+pub trait CoproductAppend<Rhs> {
+    type Output;
+
+    /// Move a value of `Self` into the concatenated Coproduct.
+    ///
+    /// Please see the [inherent method] for more information.
+    ///
+    /// [inherent method]: enum.Coproduct.html#method.extend_right
+    fn extend_right(self) -> Self::Output;
+
+    /// Move a value of `Rhs` into the concatenated Coproduct.
+    ///
+    /// Please see the [inherent method] for more information.
+    ///
+    /// [inherent method]: enum.Coproduct.html#method.extend_left
+    fn extend_left(rhs: Rhs) -> Self::Output;
+}
+
+impl<Rhs> CoproductAppend<Rhs> for CNil {
+    type Output = Rhs;
+
+    fn extend_right(self) -> Self::Output {
+        match self {}
+    }
+
+    fn extend_left(rhs: Rhs) -> Self::Output {
+        rhs
+    }
+}
+
+impl<Head, Tail, Rhs> CoproductAppend<Rhs> for Coproduct<Head, Tail>
+where
+    Tail: CoproductAppend<Rhs>,
+{
+    type Output = Coproduct<Head, <Tail as CoproductAppend<Rhs>>::Output>;
+
+    fn extend_right(self) -> Self::Output {
+        match self {
+            Coproduct::Inl(head) => Coproduct::Inl(head),
+            Coproduct::Inr(tail) => Coproduct::Inr(tail.extend_right()),
+        }
+    }
+
+    fn extend_left(rhs: Rhs) -> Self::Output {
+        Coproduct::Inr(Tail::extend_left(rhs))
+    }
+}
+
+/// Trait for merging two coproduct types, keeping a single variant of each type.
+///
+/// This trait is part of the implementation of the inherent methods
+/// [`Coproduct::union_right`] and [`Coproduct::union_left`]. Please see
+/// those methods for more information.
+///
+/// You only need to import this trait when working with generic
+/// Coproducts of unknown type.
+///
+/// [`Coproduct::union_right`]: enum.Coproduct.html#method.union_right
+/// [`Coproduct::union_left`]: enum.Coproduct.html#method.union_left
+pub trait CoproductUnion<Other, Out, Indices> {
+    /// Move a value of `Self` into the union.
+    ///
+    /// Please see the [inherent method] for more information.
+    ///
+    /// [inherent method]: enum.Coproduct.html#method.union_right
+    fn union_right(self) -> Out;
+
+    /// Move a value of `Other` into the union.
+    ///
+    /// Please see the [inherent method] for more information.
+    ///
+    /// [inherent method]: enum.Coproduct.html#method.union_left
+    fn union_left(other: Other) -> Out;
+}
+
+/// The indices are `(Added, OtherIndices, CoveredIndices)`, where `Added` is
+/// the part of `Out` that comes after the variants of `Source`. Every variant
+/// of `Added` must be reached by one of `OtherIndices`, which is what rules out
+/// junk variants; this is checked by position, so that `Other` may still hold
+/// a type twice.
+impl<Source, Other, Out, Added, OtherIndices, CoveredIndices>
+    CoproductUnion<Other, Out, (Added, OtherIndices, CoveredIndices)> for Source
+where
+    Source: CoproductAppend<Added, Output = Out> + CoverVariants<Added, OtherIndices>,
+    Other: CoproductEmbedder<Out, OtherIndices>,
+    <Source as CoverVariants<Added, OtherIndices>>::Output:
+        CoproductEmbedder<Coproduct<CoveredVariant, CNil>, CoveredIndices>,
+{
+    fn union_right(self) -> Out {
+        self.extend_right()
+    }
+
+    fn union_left(other: Other) -> Out {
+        other.embed()
+    }
+}
+
+/// Placeholder for a variant of a union that is reached by the other operand.
+///
+/// See [`CoverVariants`].
+///
+/// [`CoverVariants`]: trait.CoverVariants.html
+pub enum CoveredVariant {}
+
+/// [`CoproductUnion`] inner mechanics for marking a variant of a union that is
+/// reached by an index.
+///
+/// `Self` is the left operand of the union, and `Index` points into the whole
+/// union. If it points past `Self`, the variant it reaches in `Marks` (the
+/// rest of the union) is replaced by [`CoveredVariant`]. You only need this
+/// trait when writing generic code that takes the union of two coproducts.
+///
+/// [`CoproductUnion`]: trait.CoproductUnion.html
+/// [`CoveredVariant`]: enum.CoveredVariant.html
+pub trait CoverVariant<Marks, Index> {
+    type Output;
+}
+
+impl<H, T, Marks> CoverVariant<Marks, Here> for Coproduct<H, T> {
+    type Output = Marks;
+}
+
+impl<H, T, Marks, I> CoverVariant<Marks, There<I>> for Coproduct<H, T>
+where
+    T: CoverVariant<Marks, I>,
+{
+    type Output = <T as CoverVariant<Marks, I>>::Output;
+}
+
+impl<H, T> CoverVariant<Coproduct<H, T>, Here> for CNil {
+    type Output = Coproduct<CoveredVariant, T>;
+}
+
+impl<H, T, I> CoverVariant<Coproduct<H, T>, There<I>> for CNil
+where
+    CNil: CoverVariant<T, I>,
+{
+    type Output = Coproduct<H, <CNil as CoverVariant<T, I>>::Output>;
+}
+
+/// [`CoproductUnion`] inner mechanics for marking the variants of a union that
+/// are reached by a list of indices.
+///
+/// This applies [`CoverVariant`] for each index of `Indices`, in turn. You only
+/// need this trait when writing generic code that takes the union of two
+/// coproducts.
+///
+/// [`CoproductUnion`]: trait.CoproductUnion.html
+/// [`CoverVariant`]: trait.CoverVariant.html
+pub trait CoverVariants<Marks, Indices> {
+    type Output;
+}
+
+impl<Source, Marks> CoverVariants<Marks, HNil> for Source {
+    type Output = Marks;
+}
+
+impl<Source, Marks, I, Rest> CoverVariants<Marks, HCons<I, Rest>> for Source
+where
+    Source: CoverVariant<Marks, I>,
+    Source: CoverVariants<<Source as CoverVariant<Marks, I>>::Output, Rest>,
+{
+    type Output =
+        <Source as CoverVariants<<Source as CoverVariant<Marks, I>>::Output, Rest>>::Output;
+}
+
+/// Extension methods for widening the error of a `Result` into a Coproduct.
+///
+/// This is useful when a Coproduct is used as the error type of a function,
+/// since `?` can't convert into a Coproduct by itself. As with
+/// [`Coproduct::inject`] and [`Coproduct::embed`], the `Index`/`Indices`
+/// type parameters should be left to type inference using `_`; the target
+/// Coproduct usually has to be named, because `?` doesn't fix it.
+///
+/// This trait is included in `frunk::prelude`.
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use] extern crate frunk; fn main() {
+/// use std::num::{ParseFloatError, ParseIntError};
+/// use frunk::prelude::*;
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Negative;
+///
+/// type ParseError = Coprod!(ParseIntError, ParseFloatError);
+/// type AppError = Coprod!(Negative, ParseIntError, ParseFloatError);
+///
+/// fn parse(s: &str) -> Result<(i32, f32), ParseError> {
+///     let mut parts = s.split(',');
+///     let i = parts.next().unwrap_or("").parse().inject_err::<ParseError, _>()?;
+///     let f = parts.next().unwrap_or("").parse().inject_err::<ParseError, _>()?;
+///     Ok((i, f))
+/// }
+///
+/// fn run(s: &str) -> Result<f32, AppError> {
+///     let (i, f) = parse(s).embed_err::<AppError, _>()?;
+///     if i < 0 {
+///         return Err(AppError::inject(Negative));
+///     }
+///     Ok(i as f32 * f)
+/// }
+///
+/// assert_eq!(run("2,1.5"), Ok(3.0));
+/// assert_eq!(run("-2,1.5"), Err(AppError::inject(Negative)));
+/// assert!(run("2,x").unwrap_err().get::<ParseFloatError, _>().is_some());
+/// # }
+/// ```
+///
+/// [`Coproduct::inject`]: enum.Coproduct.html#method.inject
+/// [`Coproduct::embed`]: enum.Coproduct.html#method.embed
+pub trait ResultExt<T, E> {
+    /// Inject the error into a Coproduct that has it as one of its variants.
+    fn inject_err<C, Index>(self) -> Result<T, C>
+    where
+        C: CoprodInjector<E, Index>;
+
+    /// Embed the error, itself a Coproduct, into a Coproduct that can hold
+    /// all of its variants.
+    fn embed_err<C, Indices>(self) -> Result<T, C>
+    where
+        E: CoproductEmbedder<C, Indices>;
+}
+
+impl<T, E> ResultExt<T, E> for Result<T, E> {
+    #[inline(always)]
+    fn inject_err<C, Index>(self) -> Result<T, C>
+    where
+        C: CoprodInjector<E, Index>,
+    {
+        self.map_err(C::inject)
+    }
+
+    #[inline(always)]
+    fn embed_err<C, Indices>(self) -> Result<T, C>
+    where
+        E: CoproductEmbedder<C, Indices>,
+    {
+        self.map_err(CoproductEmbedder::embed)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::Coproduct::*;
@@ -1043,6 +1996,302 @@ mod tests {
         assert_eq!(folded, false);
     }
 
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_coproduct_display() {
+        type I32F32 = Coprod!(i32, f32);
+
+        assert_eq!(I32F32::inject(3).to_string(), "3");
+        assert_eq!(format!("{:.2}", I32F32::inject(1.5f32)), "1.50");
+        assert_eq!(format!("{:>4}", I32F32::inject(7)), "   7");
+    }
+
+    #[test]
+    fn test_coproduct_map() {
+        type I32F32 = Coprod!(i32, f32);
+        type BoolI64 = Coprod!(bool, i64);
+
+        let mapper = hlist![|i: i32| i > 0, |f: f32| f as i64];
+        let mapped: BoolI64 = I32F32::inject(3).map(mapper);
+        assert_eq!(mapped, BoolI64::inject(true));
+        let mapped: BoolI64 = I32F32::inject(-2.5f32).map(mapper);
+        assert_eq!(mapped, BoolI64::inject(-2i64));
+
+        type I32I32 = Coprod!(i32, i32);
+        let co: I32I32 = Inr(Inl(4));
+        assert_eq!(co.map(|i: i32| i * 2), Inr(Inl(8)));
+
+        let co = I32F32::inject(7);
+        let mapped = co.to_ref().map(hlist![|i: &i32| *i + 1, |f: &f32| *f]);
+        assert_eq!(mapped, Inl(8));
+        assert_eq!(co, I32F32::inject(7));
+    }
+
+    #[test]
+    fn test_coproduct_poly_map() {
+        struct Double;
+        impl Func<i32> for Double {
+            type Output = i64;
+            fn call(i: i32) -> Self::Output {
+                i64::from(i) * 2
+            }
+        }
+        impl Func<u8> for Double {
+            type Output = u16;
+            fn call(b: u8) -> Self::Output {
+                u16::from(b) * 2
+            }
+        }
+        type I32U8 = Coprod!(i32, u8);
+        let mapped = I32U8::inject(200u8).map(Poly(Double));
+        assert_eq!(mapped, <Coprod!(i64, u16)>::inject(400u16));
+
+        struct Count(usize);
+        impl<T> FuncMut<T> for Count {
+            type Output = (usize, T);
+            fn call_mut(&mut self, t: T) -> Self::Output {
+                self.0 += 1;
+                (self.0, t)
+            }
+        }
+        let mut count = Count(0);
+        let mapped = I32U8::inject(5).map(PolyMut(&mut count));
+        assert_eq!(mapped, Inl((1, 5)));
+        assert_eq!(count.0, 1);
+
+        struct Offset(i32);
+        impl FuncRef<i32> for Offset {
+            type Output = i32;
+            fn call_ref(&self, i: i32) -> Self::Output {
+                i + self.0
+            }
+        }
+        impl FuncRef<u8> for Offset {
+            type Output = i32;
+            fn call_ref(&self, b: u8) -> Self::Output {
+                i32::from(b) + self.0
+            }
+        }
+        let offset = Offset(-1);
+        let mapped = I32U8::inject(5).map(PolyRef(&offset));
+        assert_eq!(mapped, Inl(4));
+        let mapped = I32U8::inject(7u8).map(PolyRef(&offset));
+        assert_eq!(mapped, Inr(Inl(6)));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_coproduct_error() {
+        use std::error::Error;
+
+        #[derive(Debug)]
+        struct Inner;
+        impl fmt::Display for Inner {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "inner")
+            }
+        }
+        impl Error for Inner {}
+
+        #[derive(Debug)]
+        struct Outer(Inner);
+        impl fmt::Display for Outer {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "outer")
+            }
+        }
+        impl Error for Outer {
+            fn source(&self) -> Option<&(dyn Error + 'static)> {
+                Some(&self.0)
+            }
+        }
+
+        type Errors = Coprod!(Inner, Outer);
+
+        let err = Errors::inject(Outer(Inner));
+        assert_eq!(err.to_string(), "outer");
+        assert_eq!(
+            err.source().map(|e| e.to_string()),
+            Some("inner".to_string())
+        );
+        assert!(Errors::inject(Inner).source().is_none());
+    }
+
+    #[test]
+    fn test_result_ext() {
+        type I32Bool = Coprod!(i32, bool);
+        type BoolI32F32 = Coprod!(bool, i32, f32);
+
+        let r: Result<(), bool> = Err(true);
+        assert_eq!(r.inject_err::<I32Bool, _>(), Err(I32Bool::inject(true)));
+        let r: Result<u8, i32> = Ok(1);
+        assert_eq!(r.inject_err::<I32Bool, _>(), Ok(1));
+
+        let r: Result<(), I32Bool> = Err(I32Bool::inject(4));
+        assert_eq!(r.embed_err::<BoolI32F32, _>(), Err(BoolI32F32::inject(4)));
+    }
+
+    #[test]
+    fn test_coproduct_extend() {
+        type I32Bool = Coprod!(i32, bool);
+        type I32BoolI32 = Coprod!(i32, bool, i32);
+
+        let co = I32Bool::inject(5);
+        let right: I32BoolI32 = co.extend_right::<Coprod!(i32)>();
+        assert_eq!(right, Inl(5));
+        assert_eq!(right.index(), 0);
+
+        let left: Coprod!(i32, i32, bool) = co.extend_left::<Coprod!(i32)>();
+        assert_eq!(left, Inr(Inl(5)));
+
+        let right_of_nil: I32Bool = co.extend_right::<CNil>();
+        assert_eq!(right_of_nil, co);
+        let left_of_nil: I32Bool = co.extend_left::<CNil>();
+        assert_eq!(left_of_nil, co);
+
+        let from_rhs: I32BoolI32 = <I32Bool as CoproductAppend<Coprod!(i32)>>::extend_left(Inl(9));
+        assert_eq!(from_rhs.index(), 2);
+    }
+
+    #[test]
+    fn test_coproduct_union() {
+        type I32Bool = Coprod!(i32, bool);
+        type BoolF32F32 = Coprod!(bool, f32, f32);
+        type Union = Coprod!(i32, bool, f32);
+
+        let left = I32Bool::inject(true);
+        assert_eq!(
+            left.union_right::<BoolF32F32, Union, _>(),
+            Union::inject(true)
+        );
+
+        let right: BoolF32F32 = Inr(Inr(Inl(2.5)));
+        assert_eq!(
+            right.union_left::<I32Bool, Union, _>(),
+            Union::inject(2.5f32)
+        );
+        let right = BoolF32F32::inject(false);
+        assert_eq!(
+            right.union_left::<I32Bool, Union, _>(),
+            Union::inject(false)
+        );
+
+        // A union with nothing new is the left type itself
+        let same = I32Bool::inject(1).union_right::<Coprod!(bool), I32Bool, _>();
+        assert_eq!(same, I32Bool::inject(1));
+    }
+
+    #[test]
+    fn test_coproduct_result_option_conversions() {
+        type I32Bool = Coprod!(i32, bool);
+
+        let ok: Result<i32, bool> = Ok(1);
+        let co: I32Bool = ok.into();
+        assert_eq!(co, Inl(1));
+        assert_eq!(co.into_result(), Ok(1));
+
+        let co: I32Bool = Err(true).into();
+        assert_eq!(co, Inr(Inl(true)));
+        let back: Result<i32, bool> = co.into();
+        assert_eq!(back, Err(true));
+
+        let co: Coprod!(i32, ()) = Some(4).into();
+        assert_eq!(co, Inl(4));
+        let none: Coprod!(i32, ()) = None.into();
+        assert_eq!(none, Inr(Inl(())));
+        let back: Option<i32> = none.into();
+        assert_eq!(back, None);
+        assert_eq!(co.into_result(), Ok(4));
+    }
+
+    #[test]
+    fn test_coproduct_variants() {
+        type I32F32Bool = Coprod!(i32, f32, bool);
+
+        let co = I32F32Bool::inject(3.5f32);
+        assert_eq!(co.index(), 1);
+        assert_eq!(co.variant_type_name(), "f32");
+        assert_eq!(co.to_ref().variant_type_name(), "&f32");
+        assert_eq!(I32F32Bool::inject(true).index(), 2);
+
+        assert_eq!(I32F32Bool::variant_count(), 3);
+        assert_eq!(<Coprod!(i32)>::variant_count(), 1);
+        assert_eq!(CNil::VARIANT_COUNT, 0);
+    }
+
+    #[test]
+    fn test_coproduct_type_names() {
+        type I32F32 = Coprod!(i32, f32);
+
+        assert_eq!(I32F32::type_names(), hlist!["i32", "f32"]);
+        assert_eq!(CNil::type_names(), HNil);
+    }
+
+    #[test]
+    fn test_coproduct_poly_mut_fold() {
+        type I32Bool = Coprod!(i32, bool);
+
+        struct Tally {
+            ints: i32,
+            bools: usize,
+        }
+        impl FuncMut<i32> for Tally {
+            type Output = ();
+            fn call_mut(&mut self, i: i32) {
+                self.ints += i;
+            }
+        }
+        impl FuncMut<bool> for Tally {
+            type Output = ();
+            fn call_mut(&mut self, _: bool) {
+                self.bools += 1;
+            }
+        }
+
+        let mut tally = Tally { ints: 0, bools: 0 };
+        I32Bool::inject(3).fold(PolyMut(&mut tally));
+        I32Bool::inject(true).fold(PolyMut(&mut tally));
+        I32Bool::inject(4).fold(PolyMut(&mut tally));
+
+        assert_eq!(tally.ints, 7);
+        assert_eq!(tally.bools, 1);
+    }
+
+    #[test]
+    fn test_coproduct_poly_ref_fold() {
+        type I32Bool = Coprod!(i32, bool);
+
+        struct Labels {
+            yes: &'static str,
+            no: &'static str,
+        }
+        impl FuncRef<i32> for Labels {
+            type Output = &'static str;
+            fn call_ref(&self, i: i32) -> Self::Output {
+                if i != 0 {
+                    self.yes
+                } else {
+                    self.no
+                }
+            }
+        }
+        impl FuncRef<bool> for Labels {
+            type Output = &'static str;
+            fn call_ref(&self, b: bool) -> Self::Output {
+                if b {
+                    self.yes
+                } else {
+                    self.no
+                }
+            }
+        }
+
+        let labels = Labels { yes: "y", no: "n" };
+        assert_eq!(I32Bool::inject(3).fold(PolyRef(&labels)), "y");
+        assert_eq!(I32Bool::inject(false).fold(PolyRef(&labels)), "n");
+        assert_eq!(I32Bool::inject(0).fold(PolyRef(labels)), "n");
+    }
+
     #[test]
     #[cfg(feature = "std")]
     fn test_coproduct_fold_non_consuming() {
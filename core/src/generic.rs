@@ -76,6 +76,35 @@
 /// let d_person: DomainPerson = frunk::convert_from(a_person); // done
 /// # }
 /// ```
+///
+/// Enums can derive `Generic` too. Their representation is a Coproduct with
+/// one HList of fields per variant, so enums whose variants line up can be
+/// converted into each other.
+///
+/// ```rust
+/// #[macro_use] extern crate frunk;
+/// #[macro_use] extern crate frunk_core;
+///
+/// # fn main() {
+/// #[derive(Generic)]
+/// enum ApiStatus {
+///     Active { since: u32 },
+///     Banned(String),
+///     Unknown,
+/// }
+///
+/// #[derive(Generic, Debug, PartialEq)]
+/// enum DomainStatus {
+///     Enabled(u32),
+///     Disabled { reason: String },
+///     Missing,
+/// }
+///
+/// let status = ApiStatus::Banned("spam".to_string());
+/// let d_status: DomainStatus = frunk::convert_from(status);
+/// assert_eq!(d_status, DomainStatus::Disabled { reason: "spam".to_string() });
+/// # }
+/// ```
 pub trait Generic {
     /// The generic representation type.
     type Repr;
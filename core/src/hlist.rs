@@ -56,9 +56,18 @@
 //! # }
 //! ```
 
-use indices::{Here, Suffixed, There};
-use traits::{Func, IntoReverse, Poly, ToMut, ToRef};
+use indices::{FlattenLeaf, FlattenNested, Here, Suffixed, There};
+use traits::{
+    Func, FuncMut, FuncRef, IntoReverse, Poly, PolyMut, PolyRef, ToMut, ToRef, TypeNames,
+};
 
+use std::any;
+#[cfg(feature = "std")]
+use std::convert::TryFrom;
+#[cfg(feature = "std")]
+use std::error::Error;
+use std::fmt;
+use std::iter::{self, FromIterator};
 use std::ops::Add;
 
 /// Typeclass for HList-y behaviour
@@ -69,17 +78,32 @@ pub trait HList: Sized {
     /// Returns the length of a given HList type without making use of any references, or
     /// in fact, any values at all.
     ///
+    /// Being a constant, it can also be used to size arrays and in compile-time
+    /// assertions. In generic code, where `LEN` cannot be used as an array size,
+    /// see [`HListLen`] instead.
+    ///
+    /// [`HListLen`]: trait.HListLen.html
+    ///
     /// # Examples
     /// ```
     /// # #[macro_use] extern crate frunk; fn main() {
     /// use frunk::prelude::*;
     ///
-    /// assert_eq!(<Hlist![i32, bool, f32]>::LEN, 3);
+    /// type Row = Hlist![i32, bool, f32];
+    ///
+    /// assert_eq!(<Row>::LEN, 3);
+    ///
+    /// // One buffer slot per field
+    /// let widths: [usize; <Row>::LEN] = [4, 1, 4];
+    ///
+    /// // Fails to compile if the HList does not have three elements
+    /// const _: () = assert!(<Row>::LEN == 3);
+    /// # let _ = widths;
     /// # }
     /// ```
     const LEN: usize;
 
-    #[deprecated(since = "0.1.30", note = "Please use len() or static_len() instead.")]
+    #[deprecated(since = "0.1.30", note = "Please use len() or LEN instead.")]
     fn length(&self) -> u32 {
         Self::LEN as u32
     }
@@ -172,6 +196,158 @@ impl<H, T: HList> HList for HCons<H, T> {
     }
 }
 
+/// Formats the elements of an HList as a list, as in `[1, a, true]`.
+///
+/// ```
+/// # #[macro_use] extern crate frunk; fn main() {
+/// let h = hlist![1, "a", true];
+///
+/// assert_eq!(h.to_string(), "[1, a, true]");
+/// assert_eq!(hlist![].to_string(), "[]");
+/// # }
+/// ```
+impl fmt::Display for HNil {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().finish()
+    }
+}
+
+impl<H, T> fmt::Display for HCons<H, T>
+where
+    Self: HDisplayElements,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut list = f.debug_list();
+        self.display_elements(&mut list);
+        list.finish()
+    }
+}
+
+/// Trait for listing the elements of an HList with their `Display` impls
+///
+/// This is part of the implementation of `Display` for HLists.
+/// You only need it in the bounds of generic code.
+pub trait HDisplayElements {
+    /// Add each element of the HList to `list`.
+    fn display_elements(&self, list: &mut fmt::DebugList);
+}
+
+impl HDisplayElements for HNil {
+    fn display_elements(&self, _: &mut fmt::DebugList) {}
+}
+
+impl<H, T> HDisplayElements for HCons<H, T>
+where
+    H: fmt::Display,
+    T: HDisplayElements,
+{
+    fn display_elements(&self, list: &mut fmt::DebugList) {
+        list.entry(&DisplayAsDebug(&self.head));
+        self.tail.display_elements(list);
+    }
+}
+
+/// Trait for listing the elements of an HList with their `Debug` impls
+///
+/// This is part of the implementation of [`FlatDebug`].
+/// You only need it in the bounds of generic code.
+///
+/// [`FlatDebug`]: struct.FlatDebug.html
+pub trait HDebugElements {
+    /// Add each element of the HList to `list`.
+    fn debug_elements(&self, list: &mut fmt::DebugList);
+}
+
+impl HDebugElements for HNil {
+    fn debug_elements(&self, _: &mut fmt::DebugList) {}
+}
+
+impl<H, T> HDebugElements for HCons<H, T>
+where
+    H: fmt::Debug,
+    T: HDebugElements,
+{
+    fn debug_elements(&self, list: &mut fmt::DebugList) {
+        list.entry(&self.head);
+        self.tail.debug_elements(list);
+    }
+}
+
+/// Wrapper that debug-formats an HList as a flat list
+///
+/// This is returned by the inherent method [`HCons::flat_debug`]. Please
+/// see that method for more information.
+///
+/// [`HCons::flat_debug`]: struct.HCons.html#method.flat_debug
+#[derive(Clone, Copy)]
+pub struct FlatDebug<'a, L: 'a>(&'a L);
+
+impl<'a, L> fmt::Debug for FlatDebug<'a, L>
+where
+    L: HDebugElements,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut list = f.debug_list();
+        self.0.debug_elements(&mut list);
+        list.finish()
+    }
+}
+
+/// Adapter for adding `Display` values to a `fmt::DebugList`.
+struct DisplayAsDebug<'a, T: 'a>(&'a T);
+
+impl<'a, T: fmt::Display> fmt::Debug for DisplayAsDebug<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self.0, f)
+    }
+}
+
+/// Bridge between the length of an HList and const generics.
+///
+/// `L: HListLen<N>` holds exactly when `L::LEN == N`. Unlike [`HList::LEN`],
+/// `N` can be used in the types of generic code (for instance as the size of
+/// an array), or fixed to require a given length.
+///
+/// This is implemented for HLists of up to 32 elements.
+///
+/// [`HList::LEN`]: trait.HList.html#associatedconstant.LEN
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate frunk; fn main() {
+/// use frunk::hlist::HListLen;
+///
+/// fn zeroed_buffer<L: HListLen<N>, const N: usize>(_: &L) -> [u8; N] {
+///     [0; N]
+/// }
+///
+/// fn takes_a_pair<L: HListLen<2>>(_: L) {}
+///
+/// let h = hlist![1, "two", 3.0];
+/// assert_eq!(zeroed_buffer(&h), [0u8, 0, 0]);
+///
+/// takes_a_pair(hlist![1, 2]);
+/// // takes_a_pair(h);  // Won't compile.
+/// # }
+/// ```
+pub trait HListLen<const N: usize>: HList {}
+
+impl HListLen<0> for HNil {}
+
+macro_rules! hlist_len_impls {
+    ($($n:expr),*) => {
+        $(
+            impl<H, T: HListLen<{ $n - 1 }>> HListLen<$n> for HCons<H, T> {}
+        )*
+    };
+}
+
+hlist_len_impls!(
+    1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26,
+    27, 28, 29, 30, 31, 32
+);
+
 impl<H, T> HCons<H, T> {
     /// Returns the head of the list and the tail of the list as a tuple2.
     /// The original list is consumed
@@ -236,6 +412,32 @@ macro_rules! gen_inherent_methods {
                 HList::len(self)
             }
 
+            /// Returns the names of the element types of this HList, as given
+            /// by `std::any::type_name`.
+            ///
+            /// The result is an HList of `&'static str`, which can be turned
+            /// into a `Vec` or an array.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # #[macro_use] extern crate frunk; fn main() {
+            /// let names = <Hlist![i32, bool, Option<u8>]>::type_names();
+            /// assert_eq!(names.head, "i32");
+            /// assert_eq!(names.tail.head, "bool");
+            /// assert!(names.tail.tail.head.ends_with("Option<u8>"));
+            ///
+            /// let names: Vec<&str> = <Hlist![String]>::type_names().into();
+            /// assert!(names[0].ends_with("String"));
+            /// # }
+            /// ```
+            #[inline(always)]
+            pub fn type_names() -> <Self as TypeNames>::Names
+            where Self: TypeNames,
+            {
+                <Self as TypeNames>::type_names()
+            }
+
             /// Prepend an item to the current HList
             ///
             /// # Examples
@@ -256,6 +458,23 @@ macro_rules! gen_inherent_methods {
                 HList::prepend(self, h)
             }
 
+            /// Append an item to the end of the current HList
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # #[macro_use] extern crate frunk; fn main() {
+            /// let h = hlist![].push_back(1).push_back("hi");
+            /// assert_eq!(h, hlist![1, "hi"]);
+            /// # }
+            /// ```
+            #[inline(always)]
+            pub fn push_back<T>(self, t: T) -> <Self as PushBack<T>>::Output
+            where Self: PushBack<T>,
+            {
+                PushBack::push_back(self, t)
+            }
+
             /// Consume the current HList and return an HList with the requested shape.
             ///
             /// `sculpt` allows us to extract/reshape/scult the current HList into another shape,
@@ -264,6 +483,18 @@ macro_rules! gen_inherent_methods {
             /// The `Indices` type parameter allows the compiler to figure out that `Ts`
             /// and `Self` can be morphed into each other.
             ///
+            /// `Indices` is an HList holding one index per element of `Ts`: the
+            /// position of that element in what is left of `Self` once the
+            /// previous elements have been plucked out. It is normally inferred,
+            /// but when `Ts` contains the same type more than once there is no
+            /// unique solution, and it has to be written out (the aliases in
+            /// [`nat`] help with that). Naming it also lets it be reused. To
+            /// reorder by the absolute positions in `Self` instead, see
+            /// [`permute`].
+            ///
+            /// [`nat`]: ../nat/index.html
+            /// [`permute`]: #method.permute
+            ///
             /// # Examples
             ///
             /// ```
@@ -274,6 +505,22 @@ macro_rules! gen_inherent_methods {
             /// assert_eq!(remainder, hlist![true]);
             /// # }
             /// ```
+            ///
+            /// Giving the indices explicitly:
+            ///
+            /// ```
+            /// # #[macro_use] extern crate frunk; fn main() {
+            /// use frunk::nat::{N0, N2};
+            ///
+            /// // "b" is at position 2, then "a" is at position 0 of the rest
+            /// type LastStrFirst = Hlist![N2, N0];
+            ///
+            /// let h = hlist!["a", 1, "b"];
+            /// let (strs, remainder) = h.sculpt::<Hlist![&str, &str], LastStrFirst>();
+            /// assert_eq!(strs, hlist!["b", "a"]);
+            /// assert_eq!(remainder, hlist![1]);
+            /// # }
+            /// ```
             #[inline(always)]
             pub fn sculpt<Ts, Indices>(self) -> (Ts, <Self as Sculptor<Ts, Indices>>::Remainder)
             where Self: Sculptor<Ts, Indices>,
@@ -281,6 +528,164 @@ macro_rules! gen_inherent_methods {
                 Sculptor::sculpt(self)
             }
 
+            /// Reorder the elements of the current HList by position.
+            ///
+            /// `Positions` is an HList of type-level naturals (see [`nat`]),
+            /// where the `i`th entry is the position in `Self` of the element
+            /// that should end up at position `i`. Every position must appear
+            /// exactly once, otherwise this won't compile.
+            ///
+            /// Unlike [`sculpt`], this never relies on the element types, so it
+            /// works just as well for HLists holding the same type several times.
+            ///
+            /// [`nat`]: ../nat/index.html
+            /// [`sculpt`]: #method.sculpt
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # #[macro_use] extern crate frunk; fn main() {
+            /// use frunk::nat::{N0, N1, N2};
+            ///
+            /// let h = hlist!["a", "b", 3];
+            /// let permuted = h.permute::<Hlist![N2, N0, N1]>();
+            /// assert_eq!(permuted, hlist![3, "a", "b"]);
+            ///
+            /// let swapped = h.permute::<Hlist![N1, N0, N2]>();
+            /// assert_eq!(swapped, hlist!["b", "a", 3]);
+            ///
+            /// // h.permute::<Hlist![N0, N0, N1]>(); // Won't compile.
+            /// # }
+            /// ```
+            #[inline(always)]
+            pub fn permute<Positions>(self) -> <Self as Permuter<Positions>>::Output
+            where Self: Permuter<Positions>,
+            {
+                Permuter::permute(self)
+            }
+
+            /// Merge two HLists, keeping a single element of each type.
+            ///
+            /// The result holds all the elements of `self`, followed by the
+            /// elements of `other` whose types do not appear in `self` (those
+            /// that do are dropped, keeping the values from `self`).
+            ///
+            /// The compiler cannot tell types apart, only match them, so the
+            /// type of the result must be given (usually with an annotation).
+            /// It is then checked to be `self` followed by a part of `other`,
+            /// every other element of `other` is checked to have a type found
+            /// in `self`, and every type of `other` is checked to appear only
+            /// once in the result. A result holding a type of `other` twice
+            /// fails to compile, as long as `Indices` is left to inference.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # #[macro_use] extern crate frunk; fn main() {
+            /// let defaults = hlist![8080u16, "localhost"];
+            /// let overrides = hlist![true, "example.com"];
+            ///
+            /// let context: Hlist![bool, &str, u16] = overrides.union(defaults);
+            /// assert_eq!(context, hlist![true, "example.com", 8080]);
+            /// # }
+            /// ```
+            ///
+            /// Keeping both elements of a shared type is rejected:
+            ///
+            /// ```compile_fail
+            /// # #[macro_use] extern crate frunk; fn main() {
+            /// let u: Hlist![i32, &str, bool, i32] = hlist![1i32, "joe", true].union(hlist![5i32]);
+            /// # }
+            /// ```
+            #[inline(always)]
+            pub fn union<Other, Out, Indices>(self, other: Other) -> Out
+            where Self: HUnion<Other, Out, Indices>,
+            {
+                HUnion::union(self, other)
+            }
+
+            /// Keep the elements whose types also appear in another HList.
+            ///
+            /// The result is not computed: as with [`union`], its type must be
+            /// given, and is then checked to be exactly the intersection. Its
+            /// elements must be a part of `self` whose types are all found in
+            /// `other`, and the elements left out must have types that are not
+            /// in `other`. The check relies on `self` not holding the same
+            /// type twice, and on `Indices` being left to inference; a wrong
+            /// annotation then fails to compile.
+            ///
+            /// Only the types of `other` matter, so it is taken by reference.
+            ///
+            /// [`union`]: #method.union
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # #[macro_use] extern crate frunk; fn main() {
+            /// let h = hlist![1, "joe", true];
+            ///
+            /// let common: Hlist![bool, i32] = h.intersect(&hlist![false, 2f32, 0]);
+            /// assert_eq!(common, hlist![true, 1]);
+            /// # }
+            /// ```
+            ///
+            /// Leaving out an element whose type is in `other` does not compile:
+            ///
+            /// ```compile_fail
+            /// # #[macro_use] extern crate frunk; use frunk::HNil; fn main() {
+            /// let h = hlist![1, "joe", true];
+            ///
+            /// let common: HNil = h.intersect(&hlist![false, 2f32, 0]);
+            /// # }
+            /// ```
+            #[inline(always)]
+            pub fn intersect<Other, Out, Indices>(self, other: &Other) -> Out
+            where Self: HIntersect<Other, Out, Indices>,
+            {
+                HIntersect::intersect(self, other)
+            }
+
+            /// Remove the elements whose types appear in another HList.
+            ///
+            /// The result is not computed: as with [`union`], its type must be
+            /// given, and is then checked to be exactly the difference. Its
+            /// elements must be a part of `self` whose types are not in
+            /// `other`, and the elements left out must have types found in
+            /// `other`. The check relies on `self` not holding the same type
+            /// twice, and on `Indices` being left to inference; a wrong
+            /// annotation then fails to compile.
+            ///
+            /// Only the types of `other` matter, so it is taken by reference.
+            ///
+            /// [`union`]: #method.union
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # #[macro_use] extern crate frunk; fn main() {
+            /// let h = hlist![1, "joe", true];
+            ///
+            /// let rest: Hlist![&str] = h.difference(&hlist![false, 2f32, 0]);
+            /// assert_eq!(rest, hlist!["joe"]);
+            /// # }
+            /// ```
+            ///
+            /// Keeping an element whose type is in `other` does not compile:
+            ///
+            /// ```compile_fail
+            /// # #[macro_use] extern crate frunk; fn main() {
+            /// let h = hlist![1, "joe", true];
+            ///
+            /// let rest: Hlist![i32, &str, bool] = h.difference(&hlist![0, false]);
+            /// # }
+            /// ```
+            #[inline(always)]
+            pub fn difference<Other, Out, Indices>(self, other: &Other) -> Out
+            where Self: HDifference<Other, Out, Indices>,
+            {
+                HDifference::difference(self, other)
+            }
+
             /// Reverse the HList.
             ///
             /// # Examples
@@ -302,6 +707,64 @@ macro_rules! gen_inherent_methods {
                 IntoReverse::into_reverse(self)
             }
 
+            /// Concatenate an HList of HLists into a single HList.
+            ///
+            /// Only one level of nesting is removed, and every element must be an
+            /// HList. See [`deep_flatten`] for the general case, and [`concat`]
+            /// for a free function version.
+            ///
+            /// [`deep_flatten`]: #method.deep_flatten
+            /// [`concat`]: fn.concat.html
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # #[macro_use] extern crate frunk; fn main() {
+            /// let h = hlist![hlist![1, "a"], hlist![], hlist![true]];
+            /// assert_eq!(h.flatten(), hlist![1, "a", true]);
+            ///
+            /// // Nested HLists deeper down are left alone
+            /// let h = hlist![hlist![hlist![1]], hlist![2]];
+            /// assert_eq!(h.flatten(), hlist![hlist![1], 2]);
+            /// # }
+            /// ```
+            #[inline(always)]
+            pub fn flatten(self) -> <Self as HFlatten>::Output
+            where Self: HFlatten,
+            {
+                HFlatten::flatten(self)
+            }
+
+            /// Splice the elements of all nested HLists, at any depth, into a
+            /// single flat HList.
+            ///
+            /// Unlike [`flatten`], the elements may be a mix of HLists and other
+            /// values. There is no way to tell the compiler that a type is *not*
+            /// an HList though, so the type of the result must be given (usually
+            /// with an annotation), and the `Indices` are inferred from it.
+            /// An empty `hlist![]` element can be either kept or removed, and
+            /// the annotation decides which; when both choices produce the same
+            /// type, the `Indices` must be spelled out as well.
+            ///
+            /// [`flatten`]: #method.flatten
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # #[macro_use] extern crate frunk; fn main() {
+            /// let h = hlist![hlist![1, hlist!["a", 'b']], 2.0, hlist![true]];
+            ///
+            /// let flat: Hlist![i32, &str, char, f64, bool] = h.deep_flatten();
+            /// assert_eq!(flat, hlist![1, "a", 'b', 2.0, true]);
+            /// # }
+            /// ```
+            #[inline(always)]
+            pub fn deep_flatten<Out, Indices>(self) -> Out
+            where Self: HFlattenOnto<HNil, Out, Indices>,
+            {
+                HFlattenOnto::flatten_onto(self, HNil)
+            }
+
             /// Return an HList where the contents are references to
             /// the original HList on which this method was called.
             ///
@@ -350,8 +813,12 @@ macro_rules! gen_inherent_methods {
             /// * An `hlist![]` of closures (one for each element).
             /// * A single closure (for mapping an HList that is homogenous).
             /// * A single [`Poly`].
+            /// * A single [`PolyRef`], for mappers that read some context.
+            /// * A single [`PolyMut`], for mappers that need some state.
             ///
             /// [`Poly`]: ../traits/struct.Poly.html
+            /// [`PolyRef`]: ../traits/struct.PolyRef.html
+            /// [`PolyMut`]: ../traits/struct.PolyMut.html
             ///
             /// # Examples
             ///
@@ -388,6 +855,85 @@ macro_rules! gen_inherent_methods {
                 HMappable::map(self, mapper)
             }
 
+            /// Call a function on each element of an HList, for its side effects.
+            ///
+            /// This consumes the HList, visiting the elements in left-to-right
+            /// order. A variety of types are supported for the `F` argument:
+            ///
+            /// * An `hlist![]` of closures (one for each element).
+            /// * A single closure (for visiting an HList that is homogenous).
+            /// * A single [`Poly`], whose [`Func`] impls all return `()`.
+            /// * A single [`PolyRef`], whose [`FuncRef`] impls all return `()`.
+            /// * A single [`PolyMut`], whose [`FuncMut`] impls all return `()`.
+            ///
+            /// Use [`to_ref`] first to visit the elements by reference, or
+            /// [`for_each_mut`] to visit them by mutable reference.
+            ///
+            /// [`Poly`]: ../traits/struct.Poly.html
+            /// [`Func`]: ../traits/trait.Func.html
+            /// [`PolyRef`]: ../traits/struct.PolyRef.html
+            /// [`FuncRef`]: ../traits/trait.FuncRef.html
+            /// [`PolyMut`]: ../traits/struct.PolyMut.html
+            /// [`FuncMut`]: ../traits/trait.FuncMut.html
+            /// [`to_ref`]: #method.to_ref
+            /// [`for_each_mut`]: #method.for_each_mut
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # #[macro_use] extern crate frunk; fn main() {
+            /// let h = hlist![1, "two", 3.0];
+            ///
+            /// // Each closure only borrows what it uses, so a shared registry
+            /// // needs some interior mutability.
+            /// let seen = ::std::cell::RefCell::new(Vec::new());
+            /// h.to_ref().for_each(hlist![
+            ///     |i: &i32| seen.borrow_mut().push(format!("int {}", i)),
+            ///     |s: &&str| seen.borrow_mut().push(format!("str {}", s)),
+            ///     |f: &f64| seen.borrow_mut().push(format!("float {}", f))]);
+            /// assert_eq!(seen.into_inner(), vec!["int 1", "str two", "float 3"]);
+            ///
+            /// let mut sum = 0;
+            /// hlist![1, 2, 3].for_each(|i| sum += i);
+            /// assert_eq!(sum, 6);
+            /// # }
+            /// ```
+            #[inline(always)]
+            pub fn for_each<F>(self, f: F)
+            where Self: HForEachable<F>,
+            {
+                HForEachable::for_each(self, f)
+            }
+
+            /// Call a function on a mutable reference to each element of an HList.
+            ///
+            /// This is shorthand for `self.to_mut().for_each(f)`; see [`for_each`]
+            /// for the supported types of `F`.
+            ///
+            /// [`for_each`]: #method.for_each
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # #[macro_use] extern crate frunk; fn main() {
+            /// let mut h = hlist![1, false, String::from("a")];
+            ///
+            /// h.for_each_mut(hlist![
+            ///     |i: &mut i32| *i += 1,
+            ///     |b: &mut bool| *b = !*b,
+            ///     |s: &mut String| s.push('b')]);
+            /// assert_eq!(h, hlist![2, true, String::from("ab")]);
+            /// # }
+            /// ```
+            #[inline(always)]
+            pub fn for_each_mut<'a, F>(&'a mut self, f: F)
+            where
+                Self: ToMut<'a>,
+                <Self as ToMut<'a>>::Output: HForEachable<F>,
+            {
+                HForEachable::for_each(self.to_mut(), f)
+            }
+
             /// Perform a left fold over an HList.
             ///
             /// This transforms some `Hlist![A, B, C, ..., E]` into a single
@@ -396,6 +942,16 @@ macro_rules! gen_inherent_methods {
             ///
             /// * An `hlist![]` of closures (one for each element).
             /// * A single closure (for folding an HList that is homogenous).
+            /// * A single [`Poly`], implementing [`Func`] for each pair `(Acc, A)`.
+            /// * A single [`PolyRef`], implementing [`FuncRef`] for each pair `(Acc, A)`.
+            /// * A single [`PolyMut`], implementing [`FuncMut`] for each pair `(Acc, A)`.
+            ///
+            /// [`Poly`]: ../traits/struct.Poly.html
+            /// [`Func`]: ../traits/trait.Func.html
+            /// [`PolyRef`]: ../traits/struct.PolyRef.html
+            /// [`FuncRef`]: ../traits/trait.FuncRef.html
+            /// [`PolyMut`]: ../traits/struct.PolyMut.html
+            /// [`FuncMut`]: ../traits/trait.FuncMut.html
             ///
             /// The accumulator can freely change type over the course of the call.
             /// When called with a list of `N` functions, an expanded form of the
@@ -444,7 +1000,25 @@ macro_rules! gen_inherent_methods {
             ///     8918
             /// );
             ///
-            /// assert_eq!(9042f32, folded2)
+            /// assert_eq!(9042f32, folded2);
+            ///
+            /// // A `Poly` can be used to fold a list of differently typed elements
+            /// // with a single folder:
+            ///
+            /// use ::frunk::{Func, Poly};
+            ///
+            /// struct TotalLen;
+            /// impl<T> Func<(usize, Vec<T>)> for TotalLen {
+            ///     type Output = usize;
+            ///     fn call((acc, v): (usize, Vec<T>)) -> usize { acc + v.len() }
+            /// }
+            /// impl<'a> Func<(usize, &'a str)> for TotalLen {
+            ///     type Output = usize;
+            ///     fn call((acc, s): (usize, &'a str)) -> usize { acc + s.len() }
+            /// }
+            ///
+            /// let buffers = hlist![vec![1u8, 2], "abc", vec![true]];
+            /// assert_eq!(buffers.foldl(Poly(TotalLen), 0), 6);
             /// # }
             /// ```
             #[inline(always)]
@@ -467,6 +1041,16 @@ macro_rules! gen_inherent_methods {
             /// * An `hlist![]` of closures (one for each element).
             /// * A single closure (for folding an HList that is homogenous),
             ///   taken by reference.
+            /// * A single [`Poly`], implementing [`Func`] for each pair `(A, Acc)`.
+            /// * A single [`PolyRef`], implementing [`FuncRef`] for each pair `(A, Acc)`.
+            /// * A single [`PolyMut`], implementing [`FuncMut`] for each pair `(A, Acc)`.
+            ///
+            /// [`Poly`]: ../traits/struct.Poly.html
+            /// [`Func`]: ../traits/trait.Func.html
+            /// [`PolyRef`]: ../traits/struct.PolyRef.html
+            /// [`FuncRef`]: ../traits/trait.FuncRef.html
+            /// [`PolyMut`]: ../traits/struct.PolyMut.html
+            /// [`FuncMut`]: ../traits/trait.FuncMut.html
             ///
             /// The accumulator can freely change type over the course of the call.
             ///
@@ -516,7 +1100,24 @@ macro_rules! gen_inherent_methods {
             ///     1f32
             /// );
             ///
-            /// assert_eq!(9001, folded)
+            /// assert_eq!(9001, folded);
+            ///
+            /// // With a `Poly`, the folder receives `(element, folded_tail)`:
+            ///
+            /// use ::frunk::{Func, Poly};
+            ///
+            /// struct Describe;
+            /// impl Func<(i32, String)> for Describe {
+            ///     type Output = String;
+            ///     fn call((i, acc): (i32, String)) -> String { format!("{}{}", i, acc) }
+            /// }
+            /// impl Func<(bool, String)> for Describe {
+            ///     type Output = String;
+            ///     fn call((b, acc): (bool, String)) -> String { format!("{}{}", b, acc) }
+            /// }
+            ///
+            /// let described = hlist![1, true, 2].foldr(Poly(Describe), String::new());
+            /// assert_eq!(described, "1true2");
             /// # }
             /// ```
             #[inline(always)]
@@ -529,6 +1130,455 @@ macro_rules! gen_inherent_methods {
             {
                 HFoldRightable::foldr(self, folder, init)
             }
+
+            /// Zip two HLists together.
+            ///
+            /// This zips a `Hlist![A1, B1, ..., C1]` with a `Hlist![A2, B2, ..., C2]`
+            /// to make a `Hlist![(A1, A2), (B1, B2), ..., (C1, C2)]`.
+            /// Both HLists must have the same length.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # #[macro_use] extern crate frunk; fn main() {
+            /// use frunk::HNil;
+            ///
+            /// assert_eq!(HNil.zip(HNil), HNil);
+            ///
+            /// let h1 = hlist![1, false, 42f32];
+            /// let h2 = hlist![true, "foo", 2];
+            ///
+            /// let zipped = h1.zip(h2);
+            /// assert_eq!(zipped, hlist![
+            ///     (1, true),
+            ///     (false, "foo"),
+            ///     (42f32, 2),
+            /// ]);
+            /// # }
+            /// ```
+            #[inline(always)]
+            pub fn zip<Other>(self, other: Other) -> <Self as HZippable<Other>>::Zipped
+            where Self: HZippable<Other>,
+            {
+                HZippable::zip(self, other)
+            }
+
+            /// Unzip an HList of pairs into a pair of HLists.
+            ///
+            /// This is the inverse of [`zip`](#method.zip); it turns a
+            /// `Hlist![(A1, A2), (B1, B2), ..., (C1, C2)]` into
+            /// `(Hlist![A1, B1, ..., C1], Hlist![A2, B2, ..., C2])`.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # #[macro_use] extern crate frunk; fn main() {
+            /// use frunk::HNil;
+            ///
+            /// assert_eq!(HNil.unzip(), (HNil, HNil));
+            ///
+            /// let h = hlist![(1, true), (false, "foo"), (42f32, 2)];
+            ///
+            /// let (left, right) = h.unzip();
+            /// assert_eq!(left, hlist![1, false, 42f32]);
+            /// assert_eq!(right, hlist![true, "foo", 2]);
+            /// # }
+            /// ```
+            #[inline(always)]
+            pub fn unzip(
+                self,
+            ) -> (<Self as HUnzippable>::Left, <Self as HUnzippable>::Right)
+            where Self: HUnzippable,
+            {
+                HUnzippable::unzip(self)
+            }
+
+            /// Combine two HLists of the same length element-wise using binary functions.
+            ///
+            /// This transforms some `Hlist![A1, B1, ..., C1]` and `Hlist![A2, B2, ..., C2]`
+            /// into some `Hlist![T, U, ..., V]`.  A variety of types are supported
+            /// for the zipper argument:
+            ///
+            /// * An `hlist![]` of binary closures (one for each pair of elements).
+            /// * A single [`Poly`], implementing [`Func`] for each pair `(A1, A2)`.
+            ///
+            /// [`Poly`]: ../traits/struct.Poly.html
+            /// [`Func`]: ../traits/trait.Func.html
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # #[macro_use] extern crate frunk; fn main() {
+            /// let values = hlist![1, "hello", 4.5f32];
+            /// let validators = hlist![2, 4, 4f32];
+            ///
+            /// let checked = values.zip_with(
+            ///     validators,
+            ///     hlist![
+            ///         |v, min| v >= min,
+            ///         |s: &str, max| s.len() <= max,
+            ///         |f, min| f >= min,
+            ///     ],
+            /// );
+            /// assert_eq!(checked, hlist![false, false, true]);
+            ///
+            /// // A polymorphic function may be used instead of a list of closures
+            /// let summed = hlist![1, 2.5f32].zip_with(
+            ///     hlist![2, 0.5f32],
+            ///     poly_fn![
+            ///         |p: (i32, i32)| -> i32 { p.0 + p.1 },
+            ///         |p: (f32, f32)| -> f32 { p.0 + p.1 },
+            ///     ],
+            /// );
+            /// assert_eq!(summed, hlist![3, 3f32]);
+            /// # }
+            /// ```
+            #[inline(always)]
+            pub fn zip_with<Other, Zipper>(
+                self,
+                other: Other,
+                zipper: Zipper,
+            ) -> <Self as HZipWithable<Other, Zipper>>::Output
+            where Self: HZipWithable<Other, Zipper>,
+            {
+                HZipWithable::zip_with(self, other, zipper)
+            }
+
+            /// Apply a fallible function to each element of an HList.
+            ///
+            /// This works like [`map`], except that every function returns a
+            /// `Result<_, E>` (with the same `E` throughout). The elements are
+            /// visited in left-to-right order, and the first error stops the
+            /// traversal and is returned. The same types as for [`map`] are
+            /// supported for the mapper argument, save for [`PolyRef`] and [`PolyMut`]:
+            ///
+            /// * An `hlist![]` of closures (one for each element).
+            /// * A single closure (for mapping an HList that is homogenous).
+            /// * A single [`Poly`].
+            ///
+            /// [`map`]: #method.map
+            /// [`Poly`]: ../traits/struct.Poly.html
+            /// [`PolyRef`]: ../traits/struct.PolyRef.html
+            /// [`PolyMut`]: ../traits/struct.PolyMut.html
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # #[macro_use] extern crate frunk; fn main() {
+            /// let h = hlist!["1", "2.5", "true"];
+            ///
+            /// let parsed = h.try_map(hlist![
+            ///     |s: &str| s.parse::<i32>().map_err(|e| e.to_string()),
+            ///     |s: &str| s.parse::<f32>().map_err(|e| e.to_string()),
+            ///     |s: &str| s.parse::<bool>().map_err(|e| e.to_string())]);
+            /// assert_eq!(parsed, Ok(hlist![1, 2.5f32, true]));
+            ///
+            /// let h = hlist![2, 0, 4];
+            /// let halved = h.try_map(|n: i32| if n != 0 { Ok(n / 2) } else { Err("zero") });
+            /// assert_eq!(halved, Err("zero"));
+            /// # }
+            /// ```
+            #[inline(always)]
+            pub fn try_map<F, E>(self, mapper: F) -> Result<<Self as HTryMappable<F, E>>::Output, E>
+            where Self: HTryMappable<F, E>,
+            {
+                HTryMappable::try_map(self, mapper)
+            }
+
+            /// Perform a fallible left fold over an HList.
+            ///
+            /// This works like [`foldl`], except that every folding function
+            /// returns a `Result<_, E>` (with the same `E` throughout). The
+            /// first error stops the fold and is returned. The same types as for
+            /// [`foldl`] are supported for the folder argument, save for [`PolyRef`]
+            /// and [`PolyMut`]:
+            ///
+            /// * An `hlist![]` of closures (one for each element).
+            /// * A single closure (for folding an HList that is homogenous).
+            /// * A single [`Poly`], implementing [`Func`] for each pair `(Acc, A)`.
+            ///
+            /// [`foldl`]: #method.foldl
+            /// [`Poly`]: ../traits/struct.Poly.html
+            /// [`Func`]: ../traits/trait.Func.html
+            /// [`PolyRef`]: ../traits/struct.PolyRef.html
+            /// [`PolyMut`]: ../traits/struct.PolyMut.html
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # #[macro_use] extern crate frunk; fn main() {
+            /// let h = hlist![100u8, 100u8, 100u8];
+            ///
+            /// let sum = h.try_foldl(|acc: u8, n| acc.checked_add(n).ok_or("overflow"), 0);
+            /// assert_eq!(sum, Err("overflow"));
+            ///
+            /// let h = hlist![1u8, 2i32, 3u8];
+            /// let sum = h.try_foldl(
+            ///     hlist![
+            ///         |acc: i32, n: u8| Ok::<_, ()>(acc + i32::from(n)),
+            ///         |acc: i32, n: i32| Ok(acc * n),
+            ///         |acc: i32, n: u8| Ok(acc + i32::from(n))],
+            ///     0
+            /// );
+            /// assert_eq!(sum, Ok(5));
+            /// # }
+            /// ```
+            #[inline(always)]
+            pub fn try_foldl<Folder, Acc, E>(
+                self,
+                folder: Folder,
+                acc: Acc,
+            ) -> Result<<Self as HTryFoldLeftable<Folder, Acc, E>>::Output, E>
+            where Self: HTryFoldLeftable<Folder, Acc, E>,
+            {
+                HTryFoldLeftable::try_foldl(self, folder, acc)
+            }
+
+            /// Perform a left scan over an HList.
+            ///
+            /// This works like [`foldl`], but instead of only returning the
+            /// final accumulator, it returns an HList of every accumulator
+            /// value, starting with the initial one. The result therefore has
+            /// one more element than the list being scanned. The accumulators
+            /// handed to the folder are cloned to keep them in the result, so
+            /// they need to implement `Clone`.
+            ///
+            /// The same types as for [`foldl`] are supported for the folder, save
+            /// for [`PolyRef`]:
+            ///
+            /// * An `hlist![]` of closures (one for each element).
+            /// * A single closure (for scanning an HList that is homogenous).
+            /// * A single [`Poly`], implementing [`Func`] for each pair `(Acc, A)`.
+            /// * A single [`PolyMut`], implementing [`FuncMut`] for each pair `(Acc, A)`.
+            ///
+            /// [`foldl`]: #method.foldl
+            /// [`Poly`]: ../traits/struct.Poly.html
+            /// [`Func`]: ../traits/trait.Func.html
+            /// [`PolyRef`]: ../traits/struct.PolyRef.html
+            /// [`PolyMut`]: ../traits/struct.PolyMut.html
+            /// [`FuncMut`]: ../traits/trait.FuncMut.html
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # #[macro_use] extern crate frunk; fn main() {
+            /// let h = hlist![1, 2, 3];
+            ///
+            /// let running_sums = h.scanl(|acc, n| acc + n, 0);
+            /// assert_eq!(running_sums, hlist![0, 1, 3, 6]);
+            ///
+            /// // The accumulator type may change from one step to the next
+            /// let h = hlist![2, "a", true];
+            /// let steps = h.scanl(
+            ///     hlist![
+            ///         |acc: i32, n: i32| acc + n,
+            ///         |acc: i32, s: &str| format!("{}{}", acc, s),
+            ///         |acc: String, b: bool| (acc, b)],
+            ///     1
+            /// );
+            /// assert_eq!(
+            ///     steps,
+            ///     hlist![1, 3, "3a".to_string(), ("3a".to_string(), true)]);
+            /// # }
+            /// ```
+            ///
+            /// Using a [`Poly`] to compute the offsets of the fields of a
+            /// packed layout:
+            ///
+            /// ```
+            /// # #[macro_use] extern crate frunk; fn main() {
+            /// use std::mem::size_of;
+            /// use frunk::{Func, Poly};
+            ///
+            /// struct Offset;
+            ///
+            /// impl<T> Func<(usize, T)> for Offset {
+            ///     type Output = usize;
+            ///     fn call((offset, _): (usize, T)) -> Self::Output {
+            ///         offset + size_of::<T>()
+            ///     }
+            /// }
+            ///
+            /// let layout = hlist![0u8, 0u32, 0u16, 0u64];
+            /// let offsets = layout.scanl(Poly(Offset), 0);
+            /// assert_eq!(offsets, hlist![0, 1, 5, 7, 15]);
+            /// # }
+            /// ```
+            #[inline(always)]
+            pub fn scanl<Folder, Acc>(
+                self,
+                folder: Folder,
+                acc: Acc,
+            ) -> <Self as HScanLeftable<Folder, Acc>>::Output
+            where Self: HScanLeftable<Folder, Acc>,
+            {
+                HScanLeftable::scanl(self, folder, acc)
+            }
+
+            /// Split an HList in two at a position given by a type-level number.
+            ///
+            /// The first list holds the first `N` elements, and the second one
+            /// holds the rest. `N` can be spelled with the aliases in the
+            /// [`nat`] module.
+            ///
+            /// [`nat`]: ../nat/index.html
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # #[macro_use] extern crate frunk; fn main() {
+            /// use frunk::nat::{N0, N2};
+            ///
+            /// let h = hlist![1, "two", 3.0, '4'];
+            ///
+            /// let (left, right) = h.split_at::<N2>();
+            /// assert_eq!(left, hlist![1, "two"]);
+            /// assert_eq!(right, hlist![3.0, '4']);
+            ///
+            /// assert_eq!(h.split_at::<N0>(), (hlist![], h));
+            /// # }
+            /// ```
+            #[inline(always)]
+            pub fn split_at<N>(
+                self,
+            ) -> (
+                <Self as IndexSplitter<N>>::Left,
+                <Self as IndexSplitter<N>>::Right,
+            )
+            where Self: IndexSplitter<N>,
+            {
+                IndexSplitter::split_at(self)
+            }
+
+            /// Keep only the first `N` elements of an HList.
+            ///
+            /// This is the first half of [`split_at`].
+            ///
+            /// [`split_at`]: #method.split_at
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # #[macro_use] extern crate frunk; fn main() {
+            /// use frunk::nat::N2;
+            ///
+            /// let h = hlist![1, "two", 3.0];
+            /// assert_eq!(h.take::<N2>(), hlist![1, "two"]);
+            /// # }
+            /// ```
+            #[inline(always)]
+            pub fn take<N>(self) -> <Self as IndexSplitter<N>>::Left
+            where Self: IndexSplitter<N>,
+            {
+                IndexSplitter::split_at(self).0
+            }
+
+            /// Remove the first `N` elements of an HList.
+            ///
+            /// This is the second half of [`split_at`].
+            ///
+            /// [`split_at`]: #method.split_at
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # #[macro_use] extern crate frunk; fn main() {
+            /// use frunk::nat::N2;
+            ///
+            /// let h = hlist![1, "two", 3.0];
+            /// assert_eq!(h.drop::<N2>(), hlist![3.0]);
+            /// # }
+            /// ```
+            #[inline(always)]
+            pub fn drop<N>(self) -> <Self as IndexSplitter<N>>::Right
+            where Self: IndexSplitter<N>,
+            {
+                IndexSplitter::split_at(self).1
+            }
+
+            /// Build a homogeneous HList from the elements of an iterator.
+            ///
+            /// This fails with a [`LengthMismatch`] unless the iterator yields
+            /// exactly as many elements as the HList has. At most one element
+            /// past the length of the HList is pulled from the iterator, so
+            /// this also works with unbounded iterators.
+            ///
+            /// [`LengthMismatch`]: struct.LengthMismatch.html
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # #[macro_use] extern crate frunk; fn main() {
+            /// use frunk::hlist::LengthMismatch;
+            ///
+            /// let h = <Hlist![i32, i32, i32]>::try_from_iter(vec![1, 2, 3]);
+            /// assert_eq!(h, Ok(hlist![1, 2, 3]));
+            ///
+            /// let h = <Hlist![i32, i32, i32]>::try_from_iter(1..3);
+            /// assert_eq!(h, Err(LengthMismatch { expected: 3, actual: Some(2) }));
+            ///
+            /// let h = <Hlist![i32, i32, i32]>::try_from_iter(1..);
+            /// assert_eq!(h, Err(LengthMismatch { expected: 3, actual: None }));
+            /// # }
+            /// ```
+            #[inline(always)]
+            pub fn try_from_iter<T, I>(iter: I) -> Result<Self, LengthMismatch>
+            where
+                Self: HTryFromIterator<T>,
+                I: IntoIterator<Item = T>,
+            {
+                HTryFromIterator::try_from_iter(iter)
+            }
+
+            /// Call a function, using the elements of the HList as its arguments.
+            ///
+            /// Any function or closure with one parameter per element (of
+            /// matching type and in order) can be used, for up to 24 parameters.
+            /// See [`curry`] for the opposite direction.
+            ///
+            /// [`curry`]: fn.curry.html
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # #[macro_use] extern crate frunk; fn main() {
+            /// fn describe(id: i32, name: &str, admin: bool) -> String {
+            ///     format!("{}: {}{}", id, name, if admin { " (admin)" } else { "" })
+            /// }
+            ///
+            /// let args = hlist![1, "joe", true];
+            /// assert_eq!(args.apply(describe), "1: joe (admin)");
+            ///
+            /// assert_eq!(hlist![].apply(|| 42), 42);
+            /// # }
+            /// ```
+            #[inline(always)]
+            pub fn apply<F>(self, f: F) -> <Self as HApply<F>>::Output
+            where Self: HApply<F>,
+            {
+                HApply::apply(self, f)
+            }
+
+            /// Return a wrapper that debug-formats the HList as a flat list.
+            ///
+            /// The derived `Debug` impls show the nested structure of the HList
+            /// (`HCons { head: 1, tail: HCons { .. } }`). The wrapper instead
+            /// shows the elements side by side, like a slice would.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # #[macro_use] extern crate frunk; fn main() {
+            /// let h = hlist![1, "a", true];
+            ///
+            /// assert_eq!(format!("{:?}", h.flat_debug()), r#"[1, "a", true]"#);
+            /// assert_eq!(format!("{:?}", hlist![].flat_debug()), "[]");
+            /// # }
+            /// ```
+            #[inline(always)]
+            pub fn flat_debug<'a>(&'a self) -> FlatDebug<'a, Self> {
+                FlatDebug(self)
+            }
         }
     };
 }
@@ -627,474 +1677,2228 @@ impl<Head, Tail> HCons<Head, Tail> {
         Plucker::pluck(self)
     }
 
-    /// Turns an HList into nested Tuple2s, which are less troublesome to pattern match
-    /// and have a nicer type signature.
+    /// Borrow an element by position from an HList.
+    ///
+    /// The position is a type-level number, which can be spelled with the
+    /// aliases in the [`nat`] module. Unlike [`get`], this works even when
+    /// several elements have the same type.
+    ///
+    /// [`nat`]: ../nat/index.html
+    /// [`get`]: #method.get
     ///
     /// # Examples
     ///
     /// ```
     /// # #[macro_use] extern crate frunk; fn main() {
-    /// let h = hlist![1, "hello", true, 42f32];
+    /// use frunk::nat::{N0, N2};
     ///
-    /// // We now have a much nicer pattern matching experience
-    /// let (first,(second,(third, fourth))) = h.into_tuple2();
+    /// let h = hlist!["first", 2, "third"];
     ///
-    /// assert_eq!(first ,       1);
-    /// assert_eq!(second, "hello");
-    /// assert_eq!(third ,    true);
-    /// assert_eq!(fourth,   42f32);
+    /// assert_eq!(*h.get_at::<N0>(), "first");
+    /// assert_eq!(*h.get_at::<N2>(), "third");
+    /// // h.get_at::<frunk::nat::N3>();  // Won't compile.
     /// # }
     /// ```
     #[inline(always)]
-    pub fn into_tuple2(
-        self,
-    ) -> (
-        <Self as IntoTuple2>::HeadType,
-        <Self as IntoTuple2>::TailOutput,
-    )
+    pub fn get_at<N>(&self) -> &<Self as IndexSelector<N>>::Output
     where
-        Self: IntoTuple2,
+        Self: IndexSelector<N>,
     {
-        IntoTuple2::into_tuple2(self)
+        IndexSelector::get_at(self)
     }
-}
 
-impl<RHS> Add<RHS> for HNil
-where
-    RHS: HList,
-{
-    type Output = RHS;
+    /// Mutably borrow an element by position from an HList.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate frunk; fn main() {
+    /// use frunk::nat::N1;
+    ///
+    /// let mut h = hlist![1, 2, 3];
+    /// *h.get_at_mut::<N1>() = 20;
+    ///
+    /// assert_eq!(h, hlist![1, 20, 3]);
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub fn get_at_mut<N>(&mut self) -> &mut <Self as IndexSelector<N>>::Output
+    where
+        Self: IndexSelector<N>,
+    {
+        IndexSelector::get_at_mut(self)
+    }
+
+    /// Remove an element by position from an HList.
+    ///
+    /// The remaining elements are returned along with it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate frunk; fn main() {
+    /// use frunk::nat::N1;
+    ///
+    /// let h = hlist![String::from("a"), String::from("b"), 3];
+    /// let (b, rest) = h.pluck_at::<N1>();
+    ///
+    /// assert_eq!(b, "b");
+    /// assert_eq!(rest, hlist![String::from("a"), 3]);
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub fn pluck_at<N>(
+        self,
+    ) -> (
+        <Self as IndexPlucker<N>>::Output,
+        <Self as IndexPlucker<N>>::Remainder,
+    )
+    where
+        Self: IndexPlucker<N>,
+    {
+        IndexPlucker::pluck_at(self)
+    }
+
+    /// Remove the last element from the current HList
+    ///
+    /// The last element is returned along with the remaining elements,
+    /// mirroring [`pop`] which works from the front.
+    ///
+    /// [`pop`]: #method.pop
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate frunk; fn main() {
+    /// let h = hlist![1, "hi", true];
+    ///
+    /// let (last, init) = h.pop_back();
+    /// assert_eq!(last, true);
+    /// assert_eq!(init, hlist![1, "hi"]);
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub fn pop_back(self) -> (<Self as PopBack>::Last, <Self as PopBack>::Init)
+    where
+        Self: PopBack,
+    {
+        PopBack::pop_back(self)
+    }
+
+    /// Return the last element of the current HList
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate frunk; fn main() {
+    /// let h = hlist![1, "hi", true];
+    ///
+    /// assert_eq!(*h.to_ref().last(), true);
+    /// assert_eq!(h.last(), true);
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub fn last(self) -> <Self as Last>::Output
+    where
+        Self: Last,
+    {
+        Last::last(self)
+    }
+
+    /// Return all but the last element of the current HList
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate frunk; fn main() {
+    /// let h = hlist![1, "hi", true];
+    /// assert_eq!(h.init(), hlist![1, "hi"]);
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub fn init(self) -> <Self as Init>::Output
+    where
+        Self: Init,
+    {
+        Init::init(self)
+    }
+
+    /// Turns an HList into nested Tuple2s, which are less troublesome to pattern match
+    /// and have a nicer type signature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate frunk; fn main() {
+    /// let h = hlist![1, "hello", true, 42f32];
+    ///
+    /// // We now have a much nicer pattern matching experience
+    /// let (first,(second,(third, fourth))) = h.into_tuple2();
+    ///
+    /// assert_eq!(first ,       1);
+    /// assert_eq!(second, "hello");
+    /// assert_eq!(third ,    true);
+    /// assert_eq!(fourth,   42f32);
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub fn into_tuple2(
+        self,
+    ) -> (
+        <Self as IntoTuple2>::HeadType,
+        <Self as IntoTuple2>::TailOutput,
+    )
+    where
+        Self: IntoTuple2,
+    {
+        IntoTuple2::into_tuple2(self)
+    }
+
+    /// Turns an HList of `Option`s (or of `Result`s) inside out.
+    ///
+    /// An `Hlist![Option<A>, Option<B>, ...]` becomes an
+    /// `Option<Hlist![A, B, ...]>`, which is `None` as soon as any element
+    /// is `None`. Likewise, an `Hlist![Result<A, E>, Result<B, E>, ...]`
+    /// becomes a `Result<Hlist![A, B, ...], E>` holding the first error.
+    ///
+    /// All elements must use the same kind of wrapper (and, for `Result`,
+    /// the same error type). Convert the odd ones out first if needed, for
+    /// example with `Option::ok_or` or [`try_map`].
+    ///
+    /// [`try_map`]: #method.try_map
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate frunk; fn main() {
+    /// let h = hlist![Some(1), Some("joe"), Some(true)];
+    /// assert_eq!(h.sequence(), Some(hlist![1, "joe", true]));
+    ///
+    /// let h = hlist![Some(1), None::<&str>, Some(true)];
+    /// assert_eq!(h.sequence(), None);
+    ///
+    /// let h = hlist![Ok(1), Err("bad"), Err("worse")];
+    /// assert_eq!(h.sequence(), Err::<Hlist![i32, bool, f32], _>("bad"));
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub fn sequence(self) -> <Self as HSequence>::Output
+    where
+        Self: HSequence,
+    {
+        HSequence::sequence(self)
+    }
+
+    /// Turns an HList of collections into an iterator over rows.
+    ///
+    /// Every element must implement `IntoIterator`. The resulting iterator
+    /// yields HLists holding one item from each of them, and stops as soon
+    /// as any of them runs out (like `Iterator::zip`).
+    ///
+    /// Going the other way is a matter of `collect`ing rows into an HList of
+    /// collections, or of `extend`ing one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate frunk; fn main() {
+    /// let columns = hlist![vec![1u64, 2, 3], vec![0.5f32, 1.5, 2.5], vec!["a", "b", "c"]];
+    ///
+    /// let rows: Vec<_> = columns.transpose().collect();
+    /// assert_eq!(rows[1], hlist![2, 1.5, "b"]);
+    ///
+    /// let columns_again: Hlist![Vec<u64>, Vec<f32>, Vec<&str>] = rows.into_iter().collect();
+    /// assert_eq!(columns_again, hlist![vec![1, 2, 3], vec![0.5, 1.5, 2.5], vec!["a", "b", "c"]]);
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub fn transpose(self) -> HZipIter<<Self as HIntoIterators>::Iterators>
+    where
+        Self: HIntoIterators,
+    {
+        HZipIter {
+            iters: HIntoIterators::into_iterators(self),
+        }
+    }
+}
+
+impl<RHS> Add<RHS> for HNil
+where
+    RHS: HList,
+{
+    type Output = RHS;
+
+    fn add(self, rhs: RHS) -> RHS {
+        rhs
+    }
+}
+
+impl<H, T, RHS> Add<RHS> for HCons<H, T>
+where
+    T: Add<RHS>,
+    RHS: HList,
+{
+    type Output = HCons<H, <T as Add<RHS>>::Output>;
+
+    fn add(self, rhs: RHS) -> Self::Output {
+        HCons {
+            head: self.head,
+            tail: self.tail + rhs,
+        }
+    }
+}
+
+/// Trait for borrowing an HList element by type
+///
+/// This trait is part of the implementation of the inherent method
+/// [`HCons::get`]. Please see that method for more information.
+///
+/// You only need to import this trait when working with generic
+/// HLists of unknown type. If you have an HList of known type,
+/// then `list.get()` should "just work" even without the trait.
+///
+/// [`HCons::get`]: struct.HCons.html#method.get
+pub trait Selector<S, I> {
+    /// Borrow an element by type from an HList.
+    ///
+    /// Please see the [inherent method] for more information.
+    ///
+    /// The only difference between that inherent method and this
+    /// trait method is the location of the type parameters
+    /// (here, they are on the trait rather than the method).
+    ///
+    /// [inherent method]: struct.HCons.html#method.get
+    fn get(&self) -> &S;
+
+    /// Mutably borrow an element by type from an HList.
+    ///
+    /// Please see the [inherent method] for more information.
+    ///
+    /// The only difference between that inherent method and this
+    /// trait method is the location of the type parameters
+    /// (here, they are on the trait rather than the method).
+    ///
+    /// [inherent method]: struct.HCons.html#method.get_mut
+    fn get_mut(&mut self) -> &mut S;
+}
+
+impl<T, Tail> Selector<T, Here> for HCons<T, Tail> {
+    fn get(&self) -> &T {
+        &self.head
+    }
+
+    fn get_mut(&mut self) -> &mut T {
+        &mut self.head
+    }
+}
+
+impl<Head, Tail, FromTail, TailIndex> Selector<FromTail, There<TailIndex>> for HCons<Head, Tail>
+where
+    Tail: Selector<FromTail, TailIndex>,
+{
+    fn get(&self) -> &FromTail {
+        self.tail.get()
+    }
+
+    fn get_mut(&mut self) -> &mut FromTail {
+        self.tail.get_mut()
+    }
+}
+
+/// Trait defining extraction from a given HList
+///
+/// This trait is part of the implementation of the inherent method
+/// [`HCons::pluck`]. Please see that method for more information.
+///
+/// You only need to import this trait when working with generic
+/// HLists of unknown type. If you have an HList of known type,
+/// then `list.pluck()` should "just work" even without the trait.
+///
+/// [`HCons::pluck`]: struct.HCons.html#method.pluck
+pub trait Plucker<Target, Index> {
+    /// What is left after you pluck the target from the Self
+    type Remainder;
+
+    /// Remove an element by type from an HList.
+    ///
+    /// Please see the [inherent method] for more information.
+    ///
+    /// The only difference between that inherent method and this
+    /// trait method is the location of the type parameters.
+    /// (here, they are on the trait rather than the method)
+    ///
+    /// [inherent method]: struct.HCons.html#method.pluck
+    fn pluck(self) -> (Target, Self::Remainder);
+}
+
+/// Implementation when the pluck target is in head
+impl<T, Tail> Plucker<T, Here> for HCons<T, Tail> {
+    type Remainder = Tail;
+
+    fn pluck(self) -> (T, Self::Remainder) {
+        (self.head, self.tail)
+    }
+}
+
+/// Implementation when the pluck target is in the tail
+impl<Head, Tail, FromTail, TailIndex> Plucker<FromTail, There<TailIndex>> for HCons<Head, Tail>
+where
+    Tail: Plucker<FromTail, TailIndex>,
+{
+    type Remainder = HCons<Head, <Tail as Plucker<FromTail, TailIndex>>::Remainder>;
+
+    fn pluck(self) -> (FromTail, Self::Remainder) {
+        let (target, tail_remainder): (
+            FromTail,
+            <Tail as Plucker<FromTail, TailIndex>>::Remainder,
+        ) = <Tail as Plucker<FromTail, TailIndex>>::pluck(self.tail);
+        (
+            target,
+            HCons {
+                head: self.head,
+                tail: tail_remainder,
+            },
+        )
+    }
+}
+
+/// Trait for borrowing an HList element by position
+///
+/// This trait is part of the implementation of the inherent method
+/// [`HCons::get_at`]. Please see that method for more information.
+///
+/// You only need to import this trait when working with generic
+/// HLists of unknown type. If you have an HList of known type,
+/// then `list.get_at::<N>()` should "just work" even without the trait.
+///
+/// [`HCons::get_at`]: struct.HCons.html#method.get_at
+pub trait IndexSelector<N> {
+    /// The type of the element at position `N`
+    type Output;
+
+    /// Borrow an element by position from an HList.
+    ///
+    /// Please see the [inherent method] for more information.
+    ///
+    /// [inherent method]: struct.HCons.html#method.get_at
+    fn get_at(&self) -> &Self::Output;
+
+    /// Mutably borrow an element by position from an HList.
+    ///
+    /// Please see the [inherent method] for more information.
+    ///
+    /// [inherent method]: struct.HCons.html#method.get_at_mut
+    fn get_at_mut(&mut self) -> &mut Self::Output;
+}
+
+impl<Head, Tail> IndexSelector<Here> for HCons<Head, Tail> {
+    type Output = Head;
+
+    fn get_at(&self) -> &Head {
+        &self.head
+    }
+
+    fn get_at_mut(&mut self) -> &mut Head {
+        &mut self.head
+    }
+}
+
+impl<Head, Tail, N> IndexSelector<There<N>> for HCons<Head, Tail>
+where
+    Tail: IndexSelector<N>,
+{
+    type Output = <Tail as IndexSelector<N>>::Output;
+
+    fn get_at(&self) -> &Self::Output {
+        self.tail.get_at()
+    }
+
+    fn get_at_mut(&mut self) -> &mut Self::Output {
+        self.tail.get_at_mut()
+    }
+}
+
+/// Trait for removing an HList element by position
+///
+/// This trait is part of the implementation of the inherent method
+/// [`HCons::pluck_at`]. Please see that method for more information.
+///
+/// You only need to import this trait when working with generic
+/// HLists of unknown type. If you have an HList of known type,
+/// then `list.pluck_at::<N>()` should "just work" even without the trait.
+///
+/// [`HCons::pluck_at`]: struct.HCons.html#method.pluck_at
+pub trait IndexPlucker<N> {
+    /// The type of the element at position `N`
+    type Output;
+    /// What is left after you pluck the element from the Self
+    type Remainder;
+
+    /// Remove an element by position from an HList.
+    ///
+    /// Please see the [inherent method] for more information.
+    ///
+    /// [inherent method]: struct.HCons.html#method.pluck_at
+    fn pluck_at(self) -> (Self::Output, Self::Remainder);
+}
+
+impl<Head, Tail> IndexPlucker<Here> for HCons<Head, Tail> {
+    type Output = Head;
+    type Remainder = Tail;
+
+    fn pluck_at(self) -> (Head, Tail) {
+        (self.head, self.tail)
+    }
+}
+
+impl<Head, Tail, N> IndexPlucker<There<N>> for HCons<Head, Tail>
+where
+    Tail: IndexPlucker<N>,
+{
+    type Output = <Tail as IndexPlucker<N>>::Output;
+    type Remainder = HCons<Head, <Tail as IndexPlucker<N>>::Remainder>;
+
+    fn pluck_at(self) -> (Self::Output, Self::Remainder) {
+        let (target, tail_remainder) = self.tail.pluck_at();
+        (
+            target,
+            HCons {
+                head: self.head,
+                tail: tail_remainder,
+            },
+        )
+    }
+}
+
+/// Trait for splitting an HList in two at a given position
+///
+/// This trait is part of the implementation of the inherent methods
+/// [`HCons::split_at`], [`HCons::take`] and [`HCons::drop`]. Please see
+/// those methods for more information.
+///
+/// You only need to import this trait when working with generic
+/// HLists of unknown type. If you have an HList of known type,
+/// then `list.split_at::<N>()` should "just work" even without the trait.
+///
+/// [`HCons::split_at`]: struct.HCons.html#method.split_at
+/// [`HCons::take`]: struct.HCons.html#method.take
+/// [`HCons::drop`]: struct.HCons.html#method.drop
+pub trait IndexSplitter<N> {
+    /// The first `N` elements
+    type Left: HList;
+    /// The remaining elements
+    type Right: HList;
+
+    /// Split an HList in two at a given position.
+    ///
+    /// Please see the [inherent method] for more information.
+    ///
+    /// [inherent method]: struct.HCons.html#method.split_at
+    fn split_at(self) -> (Self::Left, Self::Right);
+}
+
+impl<L: HList> IndexSplitter<Here> for L {
+    type Left = HNil;
+    type Right = L;
+
+    fn split_at(self) -> (HNil, L) {
+        (HNil, self)
+    }
+}
+
+impl<Head, Tail, N> IndexSplitter<There<N>> for HCons<Head, Tail>
+where
+    Tail: IndexSplitter<N>,
+{
+    type Left = HCons<Head, <Tail as IndexSplitter<N>>::Left>;
+    type Right = <Tail as IndexSplitter<N>>::Right;
+
+    fn split_at(self) -> (Self::Left, Self::Right) {
+        let (left, right) = self.tail.split_at();
+        (
+            HCons {
+                head: self.head,
+                tail: left,
+            },
+            right,
+        )
+    }
+}
+
+/// Trait for reordering an HList by position
+///
+/// This trait is part of the implementation of the inherent method
+/// [`HCons::permute`]. Please see that method for more information.
+///
+/// You only need to import this trait when working with generic
+/// HLists of unknown type. If you have an HList of known type,
+/// then `list.permute::<Positions>()` should "just work" even without the trait.
+///
+/// [`HCons::permute`]: struct.HCons.html#method.permute
+pub trait Permuter<Positions> {
+    type Output;
+
+    /// Reorder the elements of an HList by position.
+    ///
+    /// Please see the [inherent method] for more information.
+    ///
+    /// [inherent method]: struct.HCons.html#method.permute
+    fn permute(self) -> Self::Output;
+}
+
+impl Permuter<HNil> for HNil {
+    type Output = HNil;
+
+    fn permute(self) -> Self::Output {
+        HNil
+    }
+}
+
+impl<Source, PHead, PTail> Permuter<HCons<PHead, PTail>> for Source
+where
+    Source: IndexPlucker<PHead>,
+    PTail: RemovePosition<PHead>,
+    <Source as IndexPlucker<PHead>>::Remainder: Permuter<<PTail as RemovePosition<PHead>>::Output>,
+{
+    type Output = HCons<
+        <Source as IndexPlucker<PHead>>::Output,
+        <<Source as IndexPlucker<PHead>>::Remainder as Permuter<
+            <PTail as RemovePosition<PHead>>::Output,
+        >>::Output,
+    >;
+
+    fn permute(self) -> Self::Output {
+        let (head, remainder) = self.pluck_at();
+        HCons {
+            head,
+            tail: remainder.permute(),
+        }
+    }
+}
+
+/// Type-level function adjusting positions after an element has been removed
+///
+/// For a single position `N`, the output is the position the same element
+/// has once the element at `Removed` is gone: `N` itself if it came before
+/// `Removed`, or `N - 1` if it came after. There is no output when `N` is
+/// `Removed`. For an HList of positions, each of them is adjusted.
+///
+/// This is used by [`Permuter`], and is not meant to be implemented by users.
+///
+/// [`Permuter`]: trait.Permuter.html
+pub trait RemovePosition<Removed> {
+    type Output;
+}
+
+impl<N> RemovePosition<Here> for There<N> {
+    type Output = N;
+}
+
+impl<Removed> RemovePosition<There<Removed>> for Here {
+    type Output = Here;
+}
+
+impl<N, Removed> RemovePosition<There<Removed>> for There<N>
+where
+    N: RemovePosition<Removed>,
+{
+    type Output = There<<N as RemovePosition<Removed>>::Output>;
+}
+
+impl<Removed> RemovePosition<Removed> for HNil {
+    type Output = HNil;
+}
+
+impl<Removed, H, Tail> RemovePosition<Removed> for HCons<H, Tail>
+where
+    H: RemovePosition<Removed>,
+    Tail: RemovePosition<Removed>,
+{
+    type Output =
+        HCons<<H as RemovePosition<Removed>>::Output, <Tail as RemovePosition<Removed>>::Output>;
+}
+
+/// Trait for pulling out some subset of an HList, using type inference.
+///
+/// This trait is part of the implementation of the inherent method
+/// [`HCons::sculpt`]. Please see that method for more information.
+///
+/// You only need to import this trait when working with generic
+/// HLists of unknown type. If you have an HList of known type,
+/// then `list.sculpt()` should "just work" even without the trait.
+///
+/// [`HCons::sculpt`]: struct.HCons.html#method.sculpt
+pub trait Sculptor<Target, Indices> {
+    type Remainder;
+
+    /// Consumes the current HList and returns an HList with the requested shape.
+    ///
+    /// Please see the [inherent method] for more information.
+    ///
+    /// The only difference between that inherent method and this
+    /// trait method is the location of the type parameters.
+    /// (here, they are on the trait rather than the method)
+    ///
+    /// [inherent method]: struct.HCons.html#method.sculpt
+    fn sculpt(self) -> (Target, Self::Remainder);
+}
+
+/// Implementation for when the target is an empty HList (HNil)
+///
+/// Index type is HNil because we don't need an index for finding HNil
+impl<Source> Sculptor<HNil, HNil> for Source {
+    type Remainder = Source;
+
+    #[inline(always)]
+    fn sculpt(self) -> (HNil, Self::Remainder) {
+        (HNil, self)
+    }
+}
+
+/// Implementation for when we have a non-empty HCons target
+///
+/// Indices is HCons<IndexHead, IndexTail> here because the compiler is being asked to figure out the
+/// Index for Plucking the first item of type THead out of Self and the rest (IndexTail) is for the
+/// Plucker's remainder induce.
+impl<THead, TTail, SHead, STail, IndexHead, IndexTail>
+    Sculptor<HCons<THead, TTail>, HCons<IndexHead, IndexTail>> for HCons<SHead, STail>
+where
+    HCons<SHead, STail>: Plucker<THead, IndexHead>,
+    <HCons<SHead, STail> as Plucker<THead, IndexHead>>::Remainder: Sculptor<TTail, IndexTail>,
+{
+    type Remainder = <<HCons<SHead, STail> as Plucker<THead, IndexHead>>::Remainder as Sculptor<
+        TTail,
+        IndexTail,
+    >>::Remainder;
+
+    #[inline(always)]
+    fn sculpt(self) -> (HCons<THead, TTail>, Self::Remainder) {
+        let (p, r): (
+            THead,
+            <HCons<SHead, STail> as Plucker<THead, IndexHead>>::Remainder,
+        ) = self.pluck();
+        let (tail, tail_remainder): (TTail, Self::Remainder) = r.sculpt();
+        (
+            HCons {
+                head: p,
+                tail: tail,
+            },
+            tail_remainder,
+        )
+    }
+}
+
+/// Trait for checking that every type of an HList can be found in `Self`
+///
+/// This only happens at the type level (no values are involved), using
+/// [`Selector`] for each element of `Target`. It is part of the
+/// implementation of the HList set operations, such as [`HCons::union`].
+///
+/// [`Selector`]: trait.Selector.html
+/// [`HCons::union`]: struct.HCons.html#method.union
+pub trait HContainsAll<Target, Indices> {}
+
+impl<Source> HContainsAll<HNil, HNil> for Source {}
+
+impl<Source, THead, TTail, IndexHead, IndexTail>
+    HContainsAll<HCons<THead, TTail>, HCons<IndexHead, IndexTail>> for Source
+where
+    Source: Selector<THead, IndexHead> + HContainsAll<TTail, IndexTail>,
+{
+}
+
+/// Trait for merging two HLists, keeping a single element of each type
+///
+/// This trait is part of the implementation of the inherent method
+/// [`HCons::union`]. Please see that method for more information.
+///
+/// You only need to import this trait when working with generic
+/// HLists of unknown type. If the type of everything is known,
+/// then `list.union(other)` should "just work" even without the trait.
+///
+/// [`HCons::union`]: struct.HCons.html#method.union
+pub trait HUnion<Other, Out, Indices> {
+    /// Merge two HLists, keeping a single element of each type.
+    ///
+    /// Please see the [inherent method] for more information.
+    ///
+    /// [inherent method]: struct.HCons.html#method.union
+    fn union(self, other: Other) -> Out;
+}
+
+/// The indices are `(Added, AddedIndices, DroppedIndices, OtherIndices)`,
+/// where `Added` is the part of `Other` that ends up in `Out`. Locating each
+/// type of `Other` in `Out` (with `OtherIndices`) is what rules out duplicates.
+impl<Source, Other, Out, Added, Dropped, AddedIndices, DroppedIndices, OtherIndices>
+    HUnion<Other, Out, (Added, AddedIndices, DroppedIndices, OtherIndices)> for Source
+where
+    Other: Sculptor<Added, AddedIndices, Remainder = Dropped>,
+    Source: HContainsAll<Dropped, DroppedIndices> + Add<Added, Output = Out>,
+    Out: HContainsAll<Other, OtherIndices>,
+{
+    fn union(self, other: Other) -> Out {
+        let (added, _): (Added, Dropped) = other.sculpt();
+        self + added
+    }
+}
+
+/// Trait for keeping the elements of an HList whose types appear in another
+///
+/// This trait is part of the implementation of the inherent method
+/// [`HCons::intersect`]. Please see that method for more information.
+///
+/// You only need to import this trait when working with generic
+/// HLists of unknown type. If the type of everything is known,
+/// then `list.intersect(&other)` should "just work" even without the trait.
+///
+/// [`HCons::intersect`]: struct.HCons.html#method.intersect
+pub trait HIntersect<Other, Out, Indices> {
+    /// Keep the elements whose types also appear in another HList.
+    ///
+    /// Please see the [inherent method] for more information.
+    ///
+    /// [inherent method]: struct.HCons.html#method.intersect
+    fn intersect(self, other: &Other) -> Out;
+}
+
+/// The indices are `(KeptIndices, OtherIndices, LeftIndices)`. Locating each
+/// element left out (`Left`) in `Left + Other` (with `LeftIndices`) is what
+/// rules out leaving behind a type that is in `Other`.
+impl<Source, Other, Out, Left, KeptIndices, OtherIndices, LeftIndices>
+    HIntersect<Other, Out, (KeptIndices, OtherIndices, LeftIndices)> for Source
+where
+    Source: Sculptor<Out, KeptIndices, Remainder = Left>,
+    Other: HContainsAll<Out, OtherIndices>,
+    Left: Add<Other>,
+    <Left as Add<Other>>::Output: HContainsAll<Left, LeftIndices>,
+{
+    fn intersect(self, _: &Other) -> Out {
+        self.sculpt().0
+    }
+}
+
+/// Trait for removing the elements of an HList whose types appear in another
+///
+/// This trait is part of the implementation of the inherent method
+/// [`HCons::difference`]. Please see that method for more information.
+///
+/// You only need to import this trait when working with generic
+/// HLists of unknown type. If the type of everything is known,
+/// then `list.difference(&other)` should "just work" even without the trait.
+///
+/// [`HCons::difference`]: struct.HCons.html#method.difference
+pub trait HDifference<Other, Out, Indices> {
+    /// Remove the elements whose types appear in another HList.
+    ///
+    /// Please see the [inherent method] for more information.
+    ///
+    /// [inherent method]: struct.HCons.html#method.difference
+    fn difference(self, other: &Other) -> Out;
+}
+
+/// The indices are `(KeptIndices, RemovedIndices, OutIndices)`. Locating each
+/// element kept in `Out + Other` (with `OutIndices`) is what rules out keeping
+/// a type that is in `Other`.
+impl<Source, Other, Out, Removed, KeptIndices, RemovedIndices, OutIndices>
+    HDifference<Other, Out, (KeptIndices, RemovedIndices, OutIndices)> for Source
+where
+    Source: Sculptor<Out, KeptIndices, Remainder = Removed>,
+    Other: HContainsAll<Removed, RemovedIndices>,
+    Out: Add<Other>,
+    <Out as Add<Other>>::Output: HContainsAll<Out, OutIndices>,
+{
+    fn difference(self, _: &Other) -> Out {
+        self.sculpt().0
+    }
+}
+
+/// Trait for concatenating an HList of HLists
+///
+/// This trait is part of the implementation of the inherent method
+/// [`HCons::flatten`]. Please see that method for more information.
+///
+/// You only need to import this trait when working with generic
+/// HLists of unknown type. If the type of everything is known,
+/// then `list.flatten()` should "just work" even without the trait.
+///
+/// [`HCons::flatten`]: struct.HCons.html#method.flatten
+pub trait HFlatten {
+    type Output: HList;
+
+    /// Concatenate an HList of HLists into a single HList.
+    ///
+    /// Please see the [inherent method] for more information.
+    ///
+    /// [inherent method]: struct.HCons.html#method.flatten
+    fn flatten(self) -> Self::Output;
+}
+
+impl HFlatten for HNil {
+    type Output = HNil;
+
+    fn flatten(self) -> HNil {
+        HNil
+    }
+}
+
+impl<H, Tail> HFlatten for HCons<H, Tail>
+where
+    Tail: HFlatten,
+    H: Add<<Tail as HFlatten>::Output>,
+    <H as Add<<Tail as HFlatten>::Output>>::Output: HList,
+{
+    type Output = <H as Add<<Tail as HFlatten>::Output>>::Output;
+
+    fn flatten(self) -> Self::Output {
+        self.head + self.tail.flatten()
+    }
+}
+
+/// Concatenate any number of HLists, given as an HList of HLists.
+///
+/// This is a free function version of [`HCons::flatten`].
+///
+/// [`HCons::flatten`]: struct.HCons.html#method.flatten
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate frunk; fn main() {
+/// use frunk::hlist::concat;
+///
+/// let h = concat(hlist![hlist![1, 2], hlist!["a"], hlist![], hlist![true]]);
+/// assert_eq!(h, hlist![1, 2, "a", true]);
+/// # }
+/// ```
+pub fn concat<Segments: HFlatten>(segments: Segments) -> Segments::Output {
+    segments.flatten()
+}
+
+/// Trait for deeply flattening an HList in front of some other HList
+///
+/// This trait is part of the implementation of the inherent method
+/// [`HCons::deep_flatten`], which flattens onto `HNil`. Please see that
+/// method for more information.
+///
+/// For each element, the `Indices` hold either [`FlattenLeaf`], to keep the
+/// element as it is, or [`FlattenNested`], to splice in its own elements.
+///
+/// [`HCons::deep_flatten`]: struct.HCons.html#method.deep_flatten
+/// [`FlattenLeaf`]: ../indices/enum.FlattenLeaf.html
+/// [`FlattenNested`]: ../indices/struct.FlattenNested.html
+pub trait HFlattenOnto<Suffix, Out, Indices> {
+    /// Flatten the HList, putting its elements in front of `suffix`.
+    fn flatten_onto(self, suffix: Suffix) -> Out;
+}
+
+impl<Suffix> HFlattenOnto<Suffix, Suffix, HNil> for HNil {
+    fn flatten_onto(self, suffix: Suffix) -> Suffix {
+        suffix
+    }
+}
+
+impl<H, Tail, Suffix, TailOut, TailIndices>
+    HFlattenOnto<Suffix, HCons<H, TailOut>, HCons<FlattenLeaf, TailIndices>> for HCons<H, Tail>
+where
+    Tail: HFlattenOnto<Suffix, TailOut, TailIndices>,
+{
+    fn flatten_onto(self, suffix: Suffix) -> HCons<H, TailOut> {
+        HCons {
+            head: self.head,
+            tail: self.tail.flatten_onto(suffix),
+        }
+    }
+}
+
+impl<H, Tail, Suffix, Mid, Out, HeadIndices, TailIndices>
+    HFlattenOnto<Suffix, Out, HCons<FlattenNested<Mid, HeadIndices>, TailIndices>>
+    for HCons<H, Tail>
+where
+    H: HFlattenOnto<Mid, Out, HeadIndices>,
+    Tail: HFlattenOnto<Suffix, Mid, TailIndices>,
+{
+    fn flatten_onto(self, suffix: Suffix) -> Out {
+        let mid = self.tail.flatten_onto(suffix);
+        self.head.flatten_onto(mid)
+    }
+}
+
+impl IntoReverse for HNil {
+    type Output = HNil;
+    fn into_reverse(self) -> Self::Output {
+        self
+    }
+}
+
+impl<H, Tail> IntoReverse for HCons<H, Tail>
+where
+    Tail: IntoReverse,
+    <Tail as IntoReverse>::Output: Add<HCons<H, HNil>>,
+{
+    type Output = <<Tail as IntoReverse>::Output as Add<HCons<H, HNil>>>::Output;
+
+    fn into_reverse(self) -> Self::Output {
+        self.tail.into_reverse()
+            + HCons {
+                head: self.head,
+                tail: HNil,
+            }
+    }
+}
+
+impl TypeNames for HNil {
+    type Names = HNil;
+
+    fn type_names() -> Self::Names {
+        HNil
+    }
+}
+
+impl<H, Tail> TypeNames for HCons<H, Tail>
+where
+    Tail: TypeNames,
+{
+    type Names = HCons<&'static str, <Tail as TypeNames>::Names>;
+
+    fn type_names() -> Self::Names {
+        HCons {
+            head: any::type_name::<H>(),
+            tail: Tail::type_names(),
+        }
+    }
+}
+
+impl<P, H, Tail> HMappable<Poly<P>> for HCons<H, Tail>
+where
+    P: Func<H>,
+    Tail: HMappable<Poly<P>>,
+{
+    type Output = HCons<<P as Func<H>>::Output, <Tail as HMappable<Poly<P>>>::Output>;
+    fn map(self, poly: Poly<P>) -> Self::Output {
+        HCons {
+            head: P::call(self.head),
+            tail: self.tail.map(poly),
+        }
+    }
+}
+
+impl<P, H, Tail> HMappable<PolyMut<P>> for HCons<H, Tail>
+where
+    P: FuncMut<H>,
+    Tail: HMappable<PolyMut<P>>,
+{
+    type Output = HCons<<P as FuncMut<H>>::Output, <Tail as HMappable<PolyMut<P>>>::Output>;
+    fn map(self, mut poly: PolyMut<P>) -> Self::Output {
+        let head = poly.0.call_mut(self.head);
+        HCons {
+            head,
+            tail: self.tail.map(poly),
+        }
+    }
+}
+
+impl<P, H, Tail> HMappable<PolyRef<P>> for HCons<H, Tail>
+where
+    P: FuncRef<H>,
+    Tail: HMappable<PolyRef<P>>,
+{
+    type Output = HCons<<P as FuncRef<H>>::Output, <Tail as HMappable<PolyRef<P>>>::Output>;
+    fn map(self, poly: PolyRef<P>) -> Self::Output {
+        let head = poly.0.call_ref(self.head);
+        HCons {
+            head,
+            tail: self.tail.map(poly),
+        }
+    }
+}
+
+/// Trait for mapping over an HList
+///
+/// This trait is part of the implementation of the inherent method
+/// [`HCons::map`]. Please see that method for more information.
+///
+/// You only need to import this trait when working with generic
+/// HLists or Mappers of unknown type. If the type of everything is known,
+/// then `list.map(f)` should "just work" even without the trait.
+///
+/// [`HCons::map`]: struct.HCons.html#method.map
+pub trait HMappable<Mapper> {
+    type Output;
+
+    /// Apply a function to each element of an HList.
+    ///
+    /// Please see the [inherent method] for more information.
+    ///
+    /// The only difference between that inherent method and this
+    /// trait method is the location of the type parameters.
+    /// (here, they are on the trait rather than the method)
+    ///
+    /// [inherent method]: struct.HCons.html#method.map
+    fn map(self, mapper: Mapper) -> Self::Output;
+}
+
+impl<F> HMappable<F> for HNil {
+    type Output = HNil;
+
+    fn map(self, _: F) -> Self::Output {
+        HNil
+    }
+}
+
+impl<F, R, H, Tail> HMappable<F> for HCons<H, Tail>
+where
+    F: Fn(H) -> R,
+    Tail: HMappable<F>,
+{
+    type Output = HCons<R, <Tail as HMappable<F>>::Output>;
+
+    fn map(self, f: F) -> Self::Output {
+        let HCons { head, tail } = self;
+        HCons {
+            head: f(head),
+            tail: tail.map(f),
+        }
+    }
+}
+
+impl<F, R, MapperTail, H, Tail> HMappable<HCons<F, MapperTail>> for HCons<H, Tail>
+where
+    F: FnOnce(H) -> R,
+    Tail: HMappable<MapperTail>,
+{
+    type Output = HCons<R, <Tail as HMappable<MapperTail>>::Output>;
+
+    fn map(self, mapper: HCons<F, MapperTail>) -> Self::Output {
+        let HCons { head, tail } = self;
+        HCons {
+            head: (mapper.head)(head),
+            tail: tail.map(mapper.tail),
+        }
+    }
+}
+
+/// Trait for calling a function on each element of an HList
+///
+/// This trait is part of the implementation of the inherent method
+/// [`HCons::for_each`]. Please see that method for more information.
+///
+/// You only need to import this trait when working with generic
+/// HLists or functions of unknown type. If the type of everything is known,
+/// then `list.for_each(f)` should "just work" even without the trait.
+///
+/// [`HCons::for_each`]: struct.HCons.html#method.for_each
+pub trait HForEachable<F> {
+    /// Call a function on each element of an HList.
+    ///
+    /// Please see the [inherent method] for more information.
+    ///
+    /// The only difference between that inherent method and this
+    /// trait method is the location of the type parameters.
+    /// (here, they are on the trait rather than the method)
+    ///
+    /// [inherent method]: struct.HCons.html#method.for_each
+    fn for_each(self, f: F);
+}
+
+impl<F> HForEachable<F> for HNil {
+    fn for_each(self, _: F) {}
+}
+
+impl<F, H, Tail> HForEachable<F> for HCons<H, Tail>
+where
+    F: FnMut(H),
+    Tail: HForEachable<F>,
+{
+    fn for_each(self, mut f: F) {
+        f(self.head);
+        self.tail.for_each(f)
+    }
+}
+
+impl<F, FTail, H, Tail> HForEachable<HCons<F, FTail>> for HCons<H, Tail>
+where
+    F: FnOnce(H),
+    Tail: HForEachable<FTail>,
+{
+    fn for_each(self, f: HCons<F, FTail>) {
+        (f.head)(self.head);
+        self.tail.for_each(f.tail)
+    }
+}
+
+impl<P, H, Tail> HForEachable<Poly<P>> for HCons<H, Tail>
+where
+    P: Func<H, Output = ()>,
+    Tail: HForEachable<Poly<P>>,
+{
+    fn for_each(self, poly: Poly<P>) {
+        P::call(self.head);
+        self.tail.for_each(poly)
+    }
+}
+
+impl<P, H, Tail> HForEachable<PolyMut<P>> for HCons<H, Tail>
+where
+    P: FuncMut<H, Output = ()>,
+    Tail: HForEachable<PolyMut<P>>,
+{
+    fn for_each(self, mut poly: PolyMut<P>) {
+        poly.0.call_mut(self.head);
+        self.tail.for_each(poly)
+    }
+}
+
+impl<P, H, Tail> HForEachable<PolyRef<P>> for HCons<H, Tail>
+where
+    P: FuncRef<H, Output = ()>,
+    Tail: HForEachable<PolyRef<P>>,
+{
+    fn for_each(self, poly: PolyRef<P>) {
+        poly.0.call_ref(self.head);
+        self.tail.for_each(poly)
+    }
+}
+
+/// Trait for performing a right fold over an HList
+///
+/// This trait is part of the implementation of the inherent method
+/// [`HCons::foldr`]. Please see that method for more information.
+///
+/// You only need to import this trait when working with generic
+/// HLists or Folders of unknown type. If the type of everything is known,
+/// then `list.foldr(f, init)` should "just work" even without the trait.
+///
+/// [`HCons::foldr`]: struct.HCons.html#method.foldr
+pub trait HFoldRightable<Folder, Init> {
+    type Output;
+
+    /// Perform a right fold over an HList.
+    ///
+    /// Please see the [inherent method] for more information.
+    ///
+    /// The only difference between that inherent method and this
+    /// trait method is the location of the type parameters.
+    /// (here, they are on the trait rather than the method)
+    ///
+    /// [inherent method]: struct.HCons.html#method.foldr
+    fn foldr(self, folder: Folder, i: Init) -> Self::Output;
+}
+
+impl<F, Init> HFoldRightable<F, Init> for HNil {
+    type Output = Init;
+
+    fn foldr(self, _: F, i: Init) -> Self::Output {
+        i
+    }
+}
+
+impl<F, FolderHeadR, FolderTail, H, Tail, Init> HFoldRightable<HCons<F, FolderTail>, Init>
+    for HCons<H, Tail>
+where
+    Tail: HFoldRightable<FolderTail, Init>,
+    F: FnOnce(H, <Tail as HFoldRightable<FolderTail, Init>>::Output) -> FolderHeadR,
+{
+    type Output = FolderHeadR;
+
+    fn foldr(self, folder: HCons<F, FolderTail>, init: Init) -> Self::Output {
+        let folded_tail = self.tail.foldr(folder.tail, init);
+        (folder.head)(self.head, folded_tail)
+    }
+}
+
+impl<'a, F, R, H, Tail, Init> HFoldRightable<&'a F, Init> for HCons<H, Tail>
+where
+    Tail: HFoldRightable<&'a F, Init>,
+    F: Fn(H, <Tail as HFoldRightable<&'a F, Init>>::Output) -> R,
+{
+    type Output = R;
+
+    fn foldr(self, folder: &'a F, init: Init) -> Self::Output {
+        let folded_tail = self.tail.foldr(folder, init);
+        (folder)(self.head, folded_tail)
+    }
+}
+
+impl<P, R, H, Tail, Init> HFoldRightable<Poly<P>, Init> for HCons<H, Tail>
+where
+    Tail: HFoldRightable<Poly<P>, Init>,
+    P: Func<(H, <Tail as HFoldRightable<Poly<P>, Init>>::Output), Output = R>,
+{
+    type Output = R;
+
+    fn foldr(self, poly: Poly<P>, init: Init) -> Self::Output {
+        let folded_tail = self.tail.foldr(poly, init);
+        P::call((self.head, folded_tail))
+    }
+}
+
+/// [`HFoldRightable`] inner mechanics for folding with a folder that needs to be owned.
+///
+/// A folder with context, such as [`PolyMut`], is needed by the head of the list
+/// only after the tail has been folded, so it has to be handed back by the
+/// recursive call. You only need this trait when writing generic code that
+/// folds with such a folder.
+///
+/// [`HFoldRightable`]: trait.HFoldRightable.html
+/// [`PolyMut`]: ../traits/struct.PolyMut.html
+pub trait HFoldRightableOwned<Folder, Init>: HFoldRightable<Folder, Init> {
+    /// Perform a right fold, returning the folder alongside the result.
+    fn real_foldr(self, folder: Folder, init: Init) -> (Self::Output, Folder);
+}
+
+impl<F, Init> HFoldRightableOwned<F, Init> for HNil {
+    fn real_foldr(self, f: F, i: Init) -> (Self::Output, F) {
+        (i, f)
+    }
+}
+
+impl<P, R, H, Tail, Init> HFoldRightable<PolyMut<P>, Init> for HCons<H, Tail>
+where
+    Tail: HFoldRightableOwned<PolyMut<P>, Init>,
+    P: FuncMut<(H, <Tail as HFoldRightable<PolyMut<P>, Init>>::Output), Output = R>,
+{
+    type Output = R;
+
+    fn foldr(self, poly: PolyMut<P>, init: Init) -> Self::Output {
+        self.real_foldr(poly, init).0
+    }
+}
+
+impl<P, R, H, Tail, Init> HFoldRightableOwned<PolyMut<P>, Init> for HCons<H, Tail>
+where
+    Tail: HFoldRightableOwned<PolyMut<P>, Init>,
+    P: FuncMut<(H, <Tail as HFoldRightable<PolyMut<P>, Init>>::Output), Output = R>,
+{
+    fn real_foldr(self, poly: PolyMut<P>, init: Init) -> (Self::Output, PolyMut<P>) {
+        let (folded_tail, mut poly) = self.tail.real_foldr(poly, init);
+        let folded = poly.0.call_mut((self.head, folded_tail));
+        (folded, poly)
+    }
+}
+
+impl<P, R, H, Tail, Init> HFoldRightable<PolyRef<P>, Init> for HCons<H, Tail>
+where
+    Tail: HFoldRightableOwned<PolyRef<P>, Init>,
+    P: FuncRef<(H, <Tail as HFoldRightable<PolyRef<P>, Init>>::Output), Output = R>,
+{
+    type Output = R;
+
+    fn foldr(self, poly: PolyRef<P>, init: Init) -> Self::Output {
+        self.real_foldr(poly, init).0
+    }
+}
+
+impl<P, R, H, Tail, Init> HFoldRightableOwned<PolyRef<P>, Init> for HCons<H, Tail>
+where
+    Tail: HFoldRightableOwned<PolyRef<P>, Init>,
+    P: FuncRef<(H, <Tail as HFoldRightable<PolyRef<P>, Init>>::Output), Output = R>,
+{
+    fn real_foldr(self, poly: PolyRef<P>, init: Init) -> (Self::Output, PolyRef<P>) {
+        let (folded_tail, poly) = self.tail.real_foldr(poly, init);
+        let folded = poly.0.call_ref((self.head, folded_tail));
+        (folded, poly)
+    }
+}
+
+impl<'a> ToRef<'a> for HNil {
+    type Output = HNil;
+
+    #[inline(always)]
+    fn to_ref(&'a self) -> Self::Output {
+        HNil
+    }
+}
+
+impl<'a, H, Tail> ToRef<'a> for HCons<H, Tail>
+where
+    H: 'a,
+    Tail: ToRef<'a>,
+{
+    type Output = HCons<&'a H, <Tail as ToRef<'a>>::Output>;
+
+    #[inline(always)]
+    fn to_ref(&'a self) -> Self::Output {
+        HCons {
+            head: &self.head,
+            tail: (&self.tail).to_ref(),
+        }
+    }
+}
+
+impl<'a> ToMut<'a> for HNil {
+    type Output = HNil;
+
+    #[inline(always)]
+    fn to_mut(&'a mut self) -> Self::Output {
+        HNil
+    }
+}
+
+impl<'a, H, Tail> ToMut<'a> for HCons<H, Tail>
+where
+    H: 'a,
+    Tail: ToMut<'a>,
+{
+    type Output = HCons<&'a mut H, <Tail as ToMut<'a>>::Output>;
+
+    #[inline(always)]
+    fn to_mut(&'a mut self) -> Self::Output {
+        HCons {
+            head: &mut self.head,
+            tail: self.tail.to_mut(),
+        }
+    }
+}
+
+/// Trait for performing a left fold over an HList
+///
+/// This trait is part of the implementation of the inherent method
+/// [`HCons::foldl`]. Please see that method for more information.
+///
+/// You only need to import this trait when working with generic
+/// HLists or Mappers of unknown type. If the type of everything is known,
+/// then `list.foldl(f, acc)` should "just work" even without the trait.
+///
+/// [`HCons::foldl`]: struct.HCons.html#method.foldl
+pub trait HFoldLeftable<Folder, Acc> {
+    type Output;
+
+    /// Perform a left fold over an HList.
+    ///
+    /// Please see the [inherent method] for more information.
+    ///
+    /// The only difference between that inherent method and this
+    /// trait method is the location of the type parameters.
+    /// (here, they are on the trait rather than the method)
+    ///
+    /// [inherent method]: struct.HCons.html#method.foldl
+    fn foldl(self, folder: Folder, acc: Acc) -> Self::Output;
+}
+
+impl<F, Acc> HFoldLeftable<F, Acc> for HNil {
+    type Output = Acc;
+
+    fn foldl(self, _: F, acc: Acc) -> Self::Output {
+        acc
+    }
+}
+
+impl<F, R, FTail, H, Tail, Acc> HFoldLeftable<HCons<F, FTail>, Acc> for HCons<H, Tail>
+where
+    Tail: HFoldLeftable<FTail, R>,
+    F: FnOnce(Acc, H) -> R,
+{
+    type Output = <Tail as HFoldLeftable<FTail, R>>::Output;
+
+    fn foldl(self, folder: HCons<F, FTail>, acc: Acc) -> Self::Output {
+        let HCons { head, tail } = self;
+        tail.foldl(folder.tail, (folder.head)(acc, head))
+    }
+}
+
+/// Implementation for folding over an HList using a single function that
+/// can handle all cases
+///
+/// ```
+/// # #[macro_use] extern crate frunk; fn main() {
+/// let h = hlist![1, 2, 3, 4, 5];
+///
+/// let r: isize = h.foldl(|acc, next| acc + next, 0);
+/// assert_eq!(r, 15);
+/// # }
+/// ```
+impl<F, H, Tail, Acc> HFoldLeftable<F, Acc> for HCons<H, Tail>
+where
+    Tail: HFoldLeftable<F, Acc>,
+    F: Fn(Acc, H) -> Acc,
+{
+    type Output = <Tail as HFoldLeftable<F, Acc>>::Output;
+
+    fn foldl(self, f: F, acc: Acc) -> Self::Output {
+        let HCons { head, tail } = self;
+        let acc = f(acc, head);
+        tail.foldl(f, acc)
+    }
+}
+
+impl<P, R, H, Tail, Acc> HFoldLeftable<Poly<P>, Acc> for HCons<H, Tail>
+where
+    Tail: HFoldLeftable<Poly<P>, R>,
+    P: Func<(Acc, H), Output = R>,
+{
+    type Output = <Tail as HFoldLeftable<Poly<P>, R>>::Output;
+
+    fn foldl(self, poly: Poly<P>, acc: Acc) -> Self::Output {
+        let HCons { head, tail } = self;
+        tail.foldl(poly, P::call((acc, head)))
+    }
+}
+
+impl<P, R, H, Tail, Acc> HFoldLeftable<PolyMut<P>, Acc> for HCons<H, Tail>
+where
+    Tail: HFoldLeftable<PolyMut<P>, R>,
+    P: FuncMut<(Acc, H), Output = R>,
+{
+    type Output = <Tail as HFoldLeftable<PolyMut<P>, R>>::Output;
+
+    fn foldl(self, mut poly: PolyMut<P>, acc: Acc) -> Self::Output {
+        let HCons { head, tail } = self;
+        let acc = poly.0.call_mut((acc, head));
+        tail.foldl(poly, acc)
+    }
+}
+
+impl<P, R, H, Tail, Acc> HFoldLeftable<PolyRef<P>, Acc> for HCons<H, Tail>
+where
+    Tail: HFoldLeftable<PolyRef<P>, R>,
+    P: FuncRef<(Acc, H), Output = R>,
+{
+    type Output = <Tail as HFoldLeftable<PolyRef<P>, R>>::Output;
+
+    fn foldl(self, poly: PolyRef<P>, acc: Acc) -> Self::Output {
+        let HCons { head, tail } = self;
+        let acc = poly.0.call_ref((acc, head));
+        tail.foldl(poly, acc)
+    }
+}
+
+/// Trait for mapping over an HList with functions that may fail
+///
+/// This trait is part of the implementation of the inherent method
+/// [`HCons::try_map`]. Please see that method for more information.
+///
+/// You only need to import this trait when working with generic
+/// HLists or Mappers of unknown type. If the type of everything is known,
+/// then `list.try_map(f)` should "just work" even without the trait.
+///
+/// [`HCons::try_map`]: struct.HCons.html#method.try_map
+pub trait HTryMappable<Mapper, E> {
+    type Output;
+
+    /// Apply a fallible function to each element of an HList.
+    ///
+    /// Please see the [inherent method] for more information.
+    ///
+    /// The only difference between that inherent method and this
+    /// trait method is the location of the type parameters.
+    /// (here, they are on the trait rather than the method)
+    ///
+    /// [inherent method]: struct.HCons.html#method.try_map
+    fn try_map(self, mapper: Mapper) -> Result<Self::Output, E>;
+}
+
+impl<F, E> HTryMappable<F, E> for HNil {
+    type Output = HNil;
+
+    fn try_map(self, _: F) -> Result<Self::Output, E> {
+        Ok(HNil)
+    }
+}
+
+impl<F, R, E, H, Tail> HTryMappable<F, E> for HCons<H, Tail>
+where
+    F: Fn(H) -> Result<R, E>,
+    Tail: HTryMappable<F, E>,
+{
+    type Output = HCons<R, <Tail as HTryMappable<F, E>>::Output>;
+
+    fn try_map(self, f: F) -> Result<Self::Output, E> {
+        let HCons { head, tail } = self;
+        let head = f(head)?;
+        Ok(HCons {
+            head,
+            tail: tail.try_map(f)?,
+        })
+    }
+}
+
+impl<F, R, E, MapperTail, H, Tail> HTryMappable<HCons<F, MapperTail>, E> for HCons<H, Tail>
+where
+    F: FnOnce(H) -> Result<R, E>,
+    Tail: HTryMappable<MapperTail, E>,
+{
+    type Output = HCons<R, <Tail as HTryMappable<MapperTail, E>>::Output>;
+
+    fn try_map(self, mapper: HCons<F, MapperTail>) -> Result<Self::Output, E> {
+        let f = mapper.head;
+        Ok(HCons {
+            head: f(self.head)?,
+            tail: self.tail.try_map(mapper.tail)?,
+        })
+    }
+}
+
+impl<P, R, E, H, Tail> HTryMappable<Poly<P>, E> for HCons<H, Tail>
+where
+    P: Func<H, Output = Result<R, E>>,
+    Tail: HTryMappable<Poly<P>, E>,
+{
+    type Output = HCons<R, <Tail as HTryMappable<Poly<P>, E>>::Output>;
+
+    fn try_map(self, poly: Poly<P>) -> Result<Self::Output, E> {
+        Ok(HCons {
+            head: P::call(self.head)?,
+            tail: self.tail.try_map(poly)?,
+        })
+    }
+}
+
+/// Trait for performing a left fold over an HList with functions that may fail
+///
+/// This trait is part of the implementation of the inherent method
+/// [`HCons::try_foldl`]. Please see that method for more information.
+///
+/// You only need to import this trait when working with generic
+/// HLists or Folders of unknown type. If the type of everything is known,
+/// then `list.try_foldl(f, acc)` should "just work" even without the trait.
+///
+/// [`HCons::try_foldl`]: struct.HCons.html#method.try_foldl
+pub trait HTryFoldLeftable<Folder, Acc, E> {
+    type Output;
+
+    /// Perform a fallible left fold over an HList.
+    ///
+    /// Please see the [inherent method] for more information.
+    ///
+    /// The only difference between that inherent method and this
+    /// trait method is the location of the type parameters.
+    /// (here, they are on the trait rather than the method)
+    ///
+    /// [inherent method]: struct.HCons.html#method.try_foldl
+    fn try_foldl(self, folder: Folder, acc: Acc) -> Result<Self::Output, E>;
+}
+
+impl<F, Acc, E> HTryFoldLeftable<F, Acc, E> for HNil {
+    type Output = Acc;
+
+    fn try_foldl(self, _: F, acc: Acc) -> Result<Self::Output, E> {
+        Ok(acc)
+    }
+}
+
+impl<F, E, H, Tail, Acc> HTryFoldLeftable<F, Acc, E> for HCons<H, Tail>
+where
+    F: Fn(Acc, H) -> Result<Acc, E>,
+    Tail: HTryFoldLeftable<F, Acc, E>,
+{
+    type Output = <Tail as HTryFoldLeftable<F, Acc, E>>::Output;
+
+    fn try_foldl(self, f: F, acc: Acc) -> Result<Self::Output, E> {
+        let HCons { head, tail } = self;
+        let acc = f(acc, head)?;
+        tail.try_foldl(f, acc)
+    }
+}
+
+impl<F, R, E, FTail, H, Tail, Acc> HTryFoldLeftable<HCons<F, FTail>, Acc, E> for HCons<H, Tail>
+where
+    F: FnOnce(Acc, H) -> Result<R, E>,
+    Tail: HTryFoldLeftable<FTail, R, E>,
+{
+    type Output = <Tail as HTryFoldLeftable<FTail, R, E>>::Output;
+
+    fn try_foldl(self, folder: HCons<F, FTail>, acc: Acc) -> Result<Self::Output, E> {
+        let HCons { head, tail } = self;
+        let acc = (folder.head)(acc, head)?;
+        tail.try_foldl(folder.tail, acc)
+    }
+}
+
+impl<P, R, E, H, Tail, Acc> HTryFoldLeftable<Poly<P>, Acc, E> for HCons<H, Tail>
+where
+    P: Func<(Acc, H), Output = Result<R, E>>,
+    Tail: HTryFoldLeftable<Poly<P>, R, E>,
+{
+    type Output = <Tail as HTryFoldLeftable<Poly<P>, R, E>>::Output;
+
+    fn try_foldl(self, poly: Poly<P>, acc: Acc) -> Result<Self::Output, E> {
+        let HCons { head, tail } = self;
+        let acc = P::call((acc, head))?;
+        tail.try_foldl(poly, acc)
+    }
+}
+
+/// Trait for performing a left scan over an HList
+///
+/// This trait is part of the implementation of the inherent method
+/// [`HCons::scanl`]. Please see that method for more information.
+///
+/// You only need to import this trait when working with generic
+/// HLists or Folders of unknown type. If the type of everything is known,
+/// then `list.scanl(f, acc)` should "just work" even without the trait.
+///
+/// [`HCons::scanl`]: struct.HCons.html#method.scanl
+pub trait HScanLeftable<Folder, Acc> {
+    type Output: HList;
+
+    /// Perform a left scan over an HList.
+    ///
+    /// Please see the [inherent method] for more information.
+    ///
+    /// The only difference between that inherent method and this
+    /// trait method is the location of the type parameters.
+    /// (here, they are on the trait rather than the method)
+    ///
+    /// [inherent method]: struct.HCons.html#method.scanl
+    fn scanl(self, folder: Folder, acc: Acc) -> Self::Output;
+}
+
+impl<F, Acc> HScanLeftable<F, Acc> for HNil {
+    type Output = HCons<Acc, HNil>;
+
+    fn scanl(self, _: F, acc: Acc) -> Self::Output {
+        HCons {
+            head: acc,
+            tail: HNil,
+        }
+    }
+}
+
+impl<F, R, FTail, H, Tail, Acc> HScanLeftable<HCons<F, FTail>, Acc> for HCons<H, Tail>
+where
+    Acc: Clone,
+    F: FnOnce(Acc, H) -> R,
+    Tail: HScanLeftable<FTail, R>,
+{
+    type Output = HCons<Acc, <Tail as HScanLeftable<FTail, R>>::Output>;
+
+    fn scanl(self, folder: HCons<F, FTail>, acc: Acc) -> Self::Output {
+        let HCons { head, tail } = self;
+        let next = (folder.head)(acc.clone(), head);
+        HCons {
+            head: acc,
+            tail: tail.scanl(folder.tail, next),
+        }
+    }
+}
+
+impl<F, H, Tail, Acc> HScanLeftable<F, Acc> for HCons<H, Tail>
+where
+    Acc: Clone,
+    F: Fn(Acc, H) -> Acc,
+    Tail: HScanLeftable<F, Acc>,
+{
+    type Output = HCons<Acc, <Tail as HScanLeftable<F, Acc>>::Output>;
+
+    fn scanl(self, f: F, acc: Acc) -> Self::Output {
+        let HCons { head, tail } = self;
+        let next = f(acc.clone(), head);
+        HCons {
+            head: acc,
+            tail: tail.scanl(f, next),
+        }
+    }
+}
+
+impl<P, R, H, Tail, Acc> HScanLeftable<Poly<P>, Acc> for HCons<H, Tail>
+where
+    Acc: Clone,
+    P: Func<(Acc, H), Output = R>,
+    Tail: HScanLeftable<Poly<P>, R>,
+{
+    type Output = HCons<Acc, <Tail as HScanLeftable<Poly<P>, R>>::Output>;
 
-    fn add(self, rhs: RHS) -> RHS {
-        rhs
+    fn scanl(self, poly: Poly<P>, acc: Acc) -> Self::Output {
+        let HCons { head, tail } = self;
+        let next = P::call((acc.clone(), head));
+        HCons {
+            head: acc,
+            tail: tail.scanl(poly, next),
+        }
     }
 }
 
-impl<H, T, RHS> Add<RHS> for HCons<H, T>
+impl<P, R, H, Tail, Acc> HScanLeftable<PolyMut<P>, Acc> for HCons<H, Tail>
 where
-    T: Add<RHS>,
-    RHS: HList,
+    Acc: Clone,
+    P: FuncMut<(Acc, H), Output = R>,
+    Tail: HScanLeftable<PolyMut<P>, R>,
 {
-    type Output = HCons<H, <T as Add<RHS>>::Output>;
+    type Output = HCons<Acc, <Tail as HScanLeftable<PolyMut<P>, R>>::Output>;
 
-    fn add(self, rhs: RHS) -> Self::Output {
+    fn scanl(self, mut poly: PolyMut<P>, acc: Acc) -> Self::Output {
+        let HCons { head, tail } = self;
+        let next = poly.0.call_mut((acc.clone(), head));
         HCons {
-            head: self.head,
-            tail: self.tail + rhs,
+            head: acc,
+            tail: tail.scanl(poly, next),
         }
     }
 }
 
-/// Trait for borrowing an HList element by type
+/// Trait for turning an HList of `Option`s or `Result`s inside out
 ///
 /// This trait is part of the implementation of the inherent method
-/// [`HCons::get`]. Please see that method for more information.
+/// [`HCons::sequence`]. Please see that method for more information.
 ///
 /// You only need to import this trait when working with generic
-/// HLists of unknown type. If you have an HList of known type,
-/// then `list.get()` should "just work" even without the trait.
+/// HLists of unknown type. If the type of everything is known,
+/// then `list.sequence()` should "just work" even without the trait.
 ///
-/// [`HCons::get`]: struct.HCons.html#method.get
-pub trait Selector<S, I> {
-    /// Borrow an element by type from an HList.
-    ///
-    /// Please see the [inherent method] for more information.
-    ///
-    /// The only difference between that inherent method and this
-    /// trait method is the location of the type parameters
-    /// (here, they are on the trait rather than the method).
-    ///
-    /// [inherent method]: struct.HCons.html#method.get
-    fn get(&self) -> &S;
+/// [`HCons::sequence`]: struct.HCons.html#method.sequence
+pub trait HSequence {
+    type Output;
 
-    /// Mutably borrow an element by type from an HList.
+    /// Turn an HList of `Option`s or `Result`s inside out.
     ///
     /// Please see the [inherent method] for more information.
     ///
-    /// The only difference between that inherent method and this
-    /// trait method is the location of the type parameters
-    /// (here, they are on the trait rather than the method).
-    ///
-    /// [inherent method]: struct.HCons.html#method.get_mut
-    fn get_mut(&mut self) -> &mut S;
+    /// [inherent method]: struct.HCons.html#method.sequence
+    fn sequence(self) -> Self::Output;
 }
 
-impl<T, Tail> Selector<T, Here> for HCons<T, Tail> {
-    fn get(&self) -> &T {
-        &self.head
-    }
+impl<H> HSequence for HCons<Option<H>, HNil> {
+    type Output = Option<HCons<H, HNil>>;
 
-    fn get_mut(&mut self) -> &mut T {
-        &mut self.head
+    fn sequence(self) -> Self::Output {
+        self.head.map(|head| HCons { head, tail: HNil })
     }
 }
 
-impl<Head, Tail, FromTail, TailIndex> Selector<FromTail, There<TailIndex>> for HCons<Head, Tail>
+impl<H, H2, Tail, SeqTail> HSequence for HCons<Option<H>, HCons<Option<H2>, Tail>>
 where
-    Tail: Selector<FromTail, TailIndex>,
+    HCons<Option<H2>, Tail>: HSequence<Output = Option<SeqTail>>,
 {
-    fn get(&self) -> &FromTail {
-        self.tail.get()
+    type Output = Option<HCons<H, SeqTail>>;
+
+    fn sequence(self) -> Self::Output {
+        let head = self.head?;
+        let tail = self.tail.sequence()?;
+        Some(HCons { head, tail })
     }
+}
 
-    fn get_mut(&mut self) -> &mut FromTail {
-        self.tail.get_mut()
+impl<H, E> HSequence for HCons<Result<H, E>, HNil> {
+    type Output = Result<HCons<H, HNil>, E>;
+
+    fn sequence(self) -> Self::Output {
+        self.head.map(|head| HCons { head, tail: HNil })
     }
 }
 
-/// Trait defining extraction from a given HList
+impl<H, H2, E, Tail, SeqTail> HSequence for HCons<Result<H, E>, HCons<Result<H2, E>, Tail>>
+where
+    HCons<Result<H2, E>, Tail>: HSequence<Output = Result<SeqTail, E>>,
+{
+    type Output = Result<HCons<H, SeqTail>, E>;
+
+    fn sequence(self) -> Self::Output {
+        let head = self.head?;
+        let tail = self.tail.sequence()?;
+        Ok(HCons { head, tail })
+    }
+}
+
+/// Trait for appending an element to the end of an HList
 ///
 /// This trait is part of the implementation of the inherent method
-/// [`HCons::pluck`]. Please see that method for more information.
+/// [`HCons::push_back`]. Please see that method for more information.
 ///
 /// You only need to import this trait when working with generic
-/// HLists of unknown type. If you have an HList of known type,
-/// then `list.pluck()` should "just work" even without the trait.
+/// HLists of unknown type. If the type of everything is known,
+/// then `list.push_back(x)` should "just work" even without the trait.
 ///
-/// [`HCons::pluck`]: struct.HCons.html#method.pluck
-pub trait Plucker<Target, Index> {
-    /// What is left after you pluck the target from the Self
-    type Remainder;
+/// [`HCons::push_back`]: struct.HCons.html#method.push_back
+pub trait PushBack<T> {
+    type Output: HList;
 
-    /// Remove an element by type from an HList.
+    /// Append an element to the end of an HList.
     ///
     /// Please see the [inherent method] for more information.
     ///
-    /// The only difference between that inherent method and this
-    /// trait method is the location of the type parameters.
-    /// (here, they are on the trait rather than the method)
-    ///
-    /// [inherent method]: struct.HCons.html#method.pluck
-    fn pluck(self) -> (Target, Self::Remainder);
+    /// [inherent method]: struct.HCons.html#method.push_back
+    fn push_back(self, t: T) -> Self::Output;
 }
 
-/// Implementation when the pluck target is in head
-impl<T, Tail> Plucker<T, Here> for HCons<T, Tail> {
-    type Remainder = Tail;
+impl<T> PushBack<T> for HNil {
+    type Output = HCons<T, HNil>;
 
-    fn pluck(self) -> (T, Self::Remainder) {
-        (self.head, self.tail)
+    fn push_back(self, t: T) -> Self::Output {
+        HCons {
+            head: t,
+            tail: HNil,
+        }
     }
 }
 
-/// Implementation when the pluck target is in the tail
-impl<Head, Tail, FromTail, TailIndex> Plucker<FromTail, There<TailIndex>> for HCons<Head, Tail>
+impl<T, H, Tail> PushBack<T> for HCons<H, Tail>
 where
-    Tail: Plucker<FromTail, TailIndex>,
+    Tail: PushBack<T>,
 {
-    type Remainder = HCons<Head, <Tail as Plucker<FromTail, TailIndex>>::Remainder>;
+    type Output = HCons<H, <Tail as PushBack<T>>::Output>;
 
-    fn pluck(self) -> (FromTail, Self::Remainder) {
-        let (target, tail_remainder): (
-            FromTail,
-            <Tail as Plucker<FromTail, TailIndex>>::Remainder,
-        ) = <Tail as Plucker<FromTail, TailIndex>>::pluck(self.tail);
-        (
-            target,
-            HCons {
-                head: self.head,
-                tail: tail_remainder,
-            },
-        )
+    fn push_back(self, t: T) -> Self::Output {
+        HCons {
+            head: self.head,
+            tail: self.tail.push_back(t),
+        }
     }
 }
 
-/// Trait for pulling out some subset of an HList, using type inference.
+/// Trait for removing the last element of an HList
 ///
 /// This trait is part of the implementation of the inherent method
-/// [`HCons::sculpt`]. Please see that method for more information.
+/// [`HCons::pop_back`]. Please see that method for more information.
 ///
 /// You only need to import this trait when working with generic
-/// HLists of unknown type. If you have an HList of known type,
-/// then `list.sculpt()` should "just work" even without the trait.
+/// HLists of unknown type. If the type of everything is known,
+/// then `list.pop_back()` should "just work" even without the trait.
 ///
-/// [`HCons::sculpt`]: struct.HCons.html#method.sculpt
-pub trait Sculptor<Target, Indices> {
-    type Remainder;
-
-    /// Consumes the current HList and returns an HList with the requested shape.
+/// [`HCons::pop_back`]: struct.HCons.html#method.pop_back
+pub trait PopBack {
+    /// The last element
+    type Last;
+    /// The remaining elements
+    type Init: HList;
+
+    /// Remove the last element of an HList.
     ///
     /// Please see the [inherent method] for more information.
     ///
-    /// The only difference between that inherent method and this
-    /// trait method is the location of the type parameters.
-    /// (here, they are on the trait rather than the method)
-    ///
-    /// [inherent method]: struct.HCons.html#method.sculpt
-    fn sculpt(self) -> (Target, Self::Remainder);
+    /// [inherent method]: struct.HCons.html#method.pop_back
+    fn pop_back(self) -> (Self::Last, Self::Init);
 }
 
-/// Implementation for when the target is an empty HList (HNil)
-///
-/// Index type is HNil because we don't need an index for finding HNil
-impl<Source> Sculptor<HNil, HNil> for Source {
-    type Remainder = Source;
+impl<H> PopBack for HCons<H, HNil> {
+    type Last = H;
+    type Init = HNil;
 
-    #[inline(always)]
-    fn sculpt(self) -> (HNil, Self::Remainder) {
-        (HNil, self)
+    fn pop_back(self) -> (H, HNil) {
+        (self.head, HNil)
     }
 }
 
-/// Implementation for when we have a non-empty HCons target
-///
-/// Indices is HCons<IndexHead, IndexTail> here because the compiler is being asked to figure out the
-/// Index for Plucking the first item of type THead out of Self and the rest (IndexTail) is for the
-/// Plucker's remainder induce.
-impl<THead, TTail, SHead, STail, IndexHead, IndexTail>
-    Sculptor<HCons<THead, TTail>, HCons<IndexHead, IndexTail>> for HCons<SHead, STail>
+impl<H, H2, Tail> PopBack for HCons<H, HCons<H2, Tail>>
 where
-    HCons<SHead, STail>: Plucker<THead, IndexHead>,
-    <HCons<SHead, STail> as Plucker<THead, IndexHead>>::Remainder: Sculptor<TTail, IndexTail>,
+    HCons<H2, Tail>: PopBack,
 {
-    type Remainder = <<HCons<SHead, STail> as Plucker<THead, IndexHead>>::Remainder as Sculptor<
-        TTail,
-        IndexTail,
-    >>::Remainder;
+    type Last = <HCons<H2, Tail> as PopBack>::Last;
+    type Init = HCons<H, <HCons<H2, Tail> as PopBack>::Init>;
 
-    #[inline(always)]
-    fn sculpt(self) -> (HCons<THead, TTail>, Self::Remainder) {
-        let (p, r): (
-            THead,
-            <HCons<SHead, STail> as Plucker<THead, IndexHead>>::Remainder,
-        ) = self.pluck();
-        let (tail, tail_remainder): (TTail, Self::Remainder) = r.sculpt();
+    fn pop_back(self) -> (Self::Last, Self::Init) {
+        let (last, init) = self.tail.pop_back();
         (
+            last,
             HCons {
-                head: p,
-                tail: tail,
+                head: self.head,
+                tail: init,
             },
-            tail_remainder,
         )
     }
 }
 
-impl IntoReverse for HNil {
-    type Output = HNil;
-    fn into_reverse(self) -> Self::Output {
-        self
-    }
+/// Trait for retrieving the last element of an HList
+///
+/// This trait is part of the implementation of the inherent method
+/// [`HCons::last`]. Please see that method for more information.
+///
+/// You only need to import this trait when working with generic
+/// HLists of unknown type. If the type of everything is known,
+/// then `list.last()` should "just work" even without the trait.
+///
+/// [`HCons::last`]: struct.HCons.html#method.last
+pub trait Last {
+    type Output;
+
+    /// Return the last element of an HList.
+    ///
+    /// Please see the [inherent method] for more information.
+    ///
+    /// [inherent method]: struct.HCons.html#method.last
+    fn last(self) -> Self::Output;
 }
 
-impl<H, Tail> IntoReverse for HCons<H, Tail>
-where
-    Tail: IntoReverse,
-    <Tail as IntoReverse>::Output: Add<HCons<H, HNil>>,
-{
-    type Output = <<Tail as IntoReverse>::Output as Add<HCons<H, HNil>>>::Output;
+impl<H> Last for HCons<H, HNil> {
+    type Output = H;
 
-    fn into_reverse(self) -> Self::Output {
-        self.tail.into_reverse()
-            + HCons {
-                head: self.head,
-                tail: HNil,
-            }
+    fn last(self) -> H {
+        self.head
     }
 }
 
-impl<P, H, Tail> HMappable<Poly<P>> for HCons<H, Tail>
+impl<H, H2, Tail> Last for HCons<H, HCons<H2, Tail>>
 where
-    P: Func<H>,
-    Tail: HMappable<Poly<P>>,
+    HCons<H2, Tail>: Last,
 {
-    type Output = HCons<<P as Func<H>>::Output, <Tail as HMappable<Poly<P>>>::Output>;
-    fn map(self, poly: Poly<P>) -> Self::Output {
-        HCons {
-            head: P::call(self.head),
-            tail: self.tail.map(poly),
-        }
+    type Output = <HCons<H2, Tail> as Last>::Output;
+
+    fn last(self) -> Self::Output {
+        self.tail.last()
     }
 }
 
-/// Trait for mapping over an HList
+/// Trait for removing the last element of an HList, keeping the rest
 ///
 /// This trait is part of the implementation of the inherent method
-/// [`HCons::map`]. Please see that method for more information.
+/// [`HCons::init`]. Please see that method for more information.
 ///
 /// You only need to import this trait when working with generic
-/// HLists or Mappers of unknown type. If the type of everything is known,
-/// then `list.map(f)` should "just work" even without the trait.
+/// HLists of unknown type. If the type of everything is known,
+/// then `list.init()` should "just work" even without the trait.
 ///
-/// [`HCons::map`]: struct.HCons.html#method.map
-pub trait HMappable<Mapper> {
-    type Output;
+/// [`HCons::init`]: struct.HCons.html#method.init
+pub trait Init {
+    type Output: HList;
 
-    /// Apply a function to each element of an HList.
+    /// Return all but the last element of an HList.
     ///
     /// Please see the [inherent method] for more information.
     ///
-    /// The only difference between that inherent method and this
-    /// trait method is the location of the type parameters.
-    /// (here, they are on the trait rather than the method)
-    ///
-    /// [inherent method]: struct.HCons.html#method.map
-    fn map(self, mapper: Mapper) -> Self::Output;
+    /// [inherent method]: struct.HCons.html#method.init
+    fn init(self) -> Self::Output;
 }
 
-impl<F> HMappable<F> for HNil {
+impl<H> Init for HCons<H, HNil> {
     type Output = HNil;
 
-    fn map(self, _: F) -> Self::Output {
+    fn init(self) -> HNil {
         HNil
     }
 }
 
-impl<F, R, H, Tail> HMappable<F> for HCons<H, Tail>
+impl<H, H2, Tail> Init for HCons<H, HCons<H2, Tail>>
 where
-    F: Fn(H) -> R,
-    Tail: HMappable<F>,
+    HCons<H2, Tail>: Init,
 {
-    type Output = HCons<R, <Tail as HMappable<F>>::Output>;
+    type Output = HCons<H, <HCons<H2, Tail> as Init>::Output>;
 
-    fn map(self, f: F) -> Self::Output {
-        let HCons { head, tail } = self;
+    fn init(self) -> Self::Output {
         HCons {
-            head: f(head),
-            tail: tail.map(f),
+            head: self.head,
+            tail: self.tail.init(),
         }
     }
 }
 
-impl<F, R, MapperTail, H, Tail> HMappable<HCons<F, MapperTail>> for HCons<H, Tail>
+/// Trait for turning an HList of `IntoIterator`s into an HList of iterators
+///
+/// This trait is part of the implementation of the inherent method
+/// [`HCons::transpose`]. Please see that method for more information.
+///
+/// [`HCons::transpose`]: struct.HCons.html#method.transpose
+pub trait HIntoIterators {
+    type Iterators: HIterators;
+
+    /// Call `into_iter` on every element of the HList.
+    fn into_iterators(self) -> Self::Iterators;
+}
+
+impl HIntoIterators for HNil {
+    type Iterators = HNil;
+
+    fn into_iterators(self) -> HNil {
+        HNil
+    }
+}
+
+impl<H, Tail> HIntoIterators for HCons<H, Tail>
 where
-    F: FnOnce(H) -> R,
-    Tail: HMappable<MapperTail>,
+    H: IntoIterator,
+    Tail: HIntoIterators,
 {
-    type Output = HCons<R, <Tail as HMappable<MapperTail>>::Output>;
+    type Iterators = HCons<H::IntoIter, <Tail as HIntoIterators>::Iterators>;
 
-    fn map(self, mapper: HCons<F, MapperTail>) -> Self::Output {
-        let HCons { head, tail } = self;
+    fn into_iterators(self) -> Self::Iterators {
         HCons {
-            head: (mapper.head)(head),
-            tail: tail.map(mapper.tail),
+            head: self.head.into_iter(),
+            tail: self.tail.into_iterators(),
         }
     }
 }
 
-/// Trait for performing a right fold over an HList
+/// Trait for advancing an HList of iterators in lockstep
 ///
-/// This trait is part of the implementation of the inherent method
-/// [`HCons::foldr`]. Please see that method for more information.
-///
-/// You only need to import this trait when working with generic
-/// HLists or Folders of unknown type. If the type of everything is known,
-/// then `list.foldr(f, init)` should "just work" even without the trait.
+/// This trait is part of the implementation of [`HZipIter`].
 ///
-/// [`HCons::foldr`]: struct.HCons.html#method.foldr
-pub trait HFoldRightable<Folder, Init> {
-    type Output;
+/// [`HZipIter`]: struct.HZipIter.html
+pub trait HIterators {
+    /// An HList holding one item of each iterator
+    type Item;
 
-    /// Perform a right fold over an HList.
-    ///
-    /// Please see the [inherent method] for more information.
-    ///
-    /// The only difference between that inherent method and this
-    /// trait method is the location of the type parameters.
-    /// (here, they are on the trait rather than the method)
-    ///
-    /// [inherent method]: struct.HCons.html#method.foldr
-    fn foldr(self, folder: Folder, i: Init) -> Self::Output;
+    /// Advance every iterator, returning `None` if any of them is exhausted.
+    fn next_row(&mut self) -> Option<Self::Item>;
+
+    /// Bounds on the number of remaining rows, as in `Iterator::size_hint`.
+    fn rows_hint(&self) -> (usize, Option<usize>);
 }
 
-impl<F, Init> HFoldRightable<F, Init> for HNil {
-    type Output = Init;
+impl HIterators for HNil {
+    type Item = HNil;
 
-    fn foldr(self, _: F, i: Init) -> Self::Output {
-        i
+    fn next_row(&mut self) -> Option<HNil> {
+        Some(HNil)
+    }
+
+    fn rows_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
     }
 }
 
-impl<F, FolderHeadR, FolderTail, H, Tail, Init> HFoldRightable<HCons<F, FolderTail>, Init>
-    for HCons<H, Tail>
+impl<H, Tail> HIterators for HCons<H, Tail>
 where
-    Tail: HFoldRightable<FolderTail, Init>,
-    F: FnOnce(H, <Tail as HFoldRightable<FolderTail, Init>>::Output) -> FolderHeadR,
+    H: Iterator,
+    Tail: HIterators,
 {
-    type Output = FolderHeadR;
+    type Item = HCons<H::Item, <Tail as HIterators>::Item>;
 
-    fn foldr(self, folder: HCons<F, FolderTail>, init: Init) -> Self::Output {
-        let folded_tail = self.tail.foldr(folder.tail, init);
-        (folder.head)(self.head, folded_tail)
+    fn next_row(&mut self) -> Option<Self::Item> {
+        let head = self.head.next()?;
+        let tail = self.tail.next_row()?;
+        Some(HCons { head, tail })
+    }
+
+    fn rows_hint(&self) -> (usize, Option<usize>) {
+        let (head_lo, head_hi) = self.head.size_hint();
+        let (tail_lo, tail_hi) = self.tail.rows_hint();
+        let hi = match (head_hi, tail_hi) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, None) => a,
+            (None, b) => b,
+        };
+        (head_lo.min(tail_lo), hi)
     }
 }
 
-impl<'a, F, R, H, Tail, Init> HFoldRightable<&'a F, Init> for HCons<H, Tail>
+/// An iterator over the rows of an HList of iterators
+///
+/// This is returned by the inherent method [`HCons::transpose`]. Please
+/// see that method for more information.
+///
+/// [`HCons::transpose`]: struct.HCons.html#method.transpose
+#[derive(Debug, Clone)]
+pub struct HZipIter<Iters> {
+    iters: Iters,
+}
+
+impl<H, Tail> Iterator for HZipIter<HCons<H, Tail>>
 where
-    Tail: HFoldRightable<&'a F, Init>,
-    F: Fn(H, <Tail as HFoldRightable<&'a F, Init>>::Output) -> R,
+    HCons<H, Tail>: HIterators,
 {
-    type Output = R;
+    type Item = <HCons<H, Tail> as HIterators>::Item;
 
-    fn foldr(self, folder: &'a F, init: Init) -> Self::Output {
-        let folded_tail = self.tail.foldr(folder, init);
-        (folder)(self.head, folded_tail)
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iters.next_row()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iters.rows_hint()
     }
 }
 
-impl<'a> ToRef<'a> for HNil {
-    type Output = HNil;
+/// Trait for zipping HLists
+///
+/// This trait is part of the implementation of the inherent method
+/// [`HCons::zip`]. Please see that method for more information.
+///
+/// You only need to import this trait when working with generic
+/// HLists of unknown type. If the type of everything is known,
+/// then `list.zip(list2)` should "just work" even without the trait.
+///
+/// [`HCons::zip`]: struct.HCons.html#method.zip
+pub trait HZippable<Other> {
+    type Zipped: HList;
 
-    #[inline(always)]
-    fn to_ref(&'a self) -> Self::Output {
+    /// Zip this HList with another one.
+    ///
+    /// Please see the [inherent method] for more information.
+    ///
+    /// [inherent method]: struct.HCons.html#method.zip
+    fn zip(self, other: Other) -> Self::Zipped;
+}
+
+impl HZippable<HNil> for HNil {
+    type Zipped = HNil;
+
+    fn zip(self, _other: HNil) -> Self::Zipped {
         HNil
     }
 }
 
-impl<'a, H, Tail> ToRef<'a> for HCons<H, Tail>
+impl<H1, T1, H2, T2> HZippable<HCons<H2, T2>> for HCons<H1, T1>
 where
-    H: 'a,
-    Tail: ToRef<'a>,
+    T1: HZippable<T2>,
 {
-    type Output = HCons<&'a H, <Tail as ToRef<'a>>::Output>;
+    type Zipped = HCons<(H1, H2), <T1 as HZippable<T2>>::Zipped>;
 
-    #[inline(always)]
-    fn to_ref(&'a self) -> Self::Output {
+    fn zip(self, other: HCons<H2, T2>) -> Self::Zipped {
         HCons {
-            head: &self.head,
-            tail: (&self.tail).to_ref(),
+            head: (self.head, other.head),
+            tail: self.tail.zip(other.tail),
         }
     }
 }
 
-impl<'a> ToMut<'a> for HNil {
-    type Output = HNil;
+/// Trait for unzipping an HList of pairs
+///
+/// This trait is part of the implementation of the inherent method
+/// [`HCons::unzip`]. Please see that method for more information.
+///
+/// You only need to import this trait when working with generic
+/// HLists of unknown type. If the type of everything is known,
+/// then `list.unzip()` should "just work" even without the trait.
+///
+/// [`HCons::unzip`]: struct.HCons.html#method.unzip
+pub trait HUnzippable {
+    /// The HList made from the first element of each pair
+    type Left: HList;
 
-    #[inline(always)]
-    fn to_mut(&'a mut self) -> Self::Output {
-        HNil
+    /// The HList made from the second element of each pair
+    type Right: HList;
+
+    /// Unzip an HList of pairs into a pair of HLists.
+    ///
+    /// Please see the [inherent method] for more information.
+    ///
+    /// [inherent method]: struct.HCons.html#method.unzip
+    fn unzip(self) -> (Self::Left, Self::Right);
+}
+
+impl HUnzippable for HNil {
+    type Left = HNil;
+    type Right = HNil;
+
+    fn unzip(self) -> (Self::Left, Self::Right) {
+        (HNil, HNil)
     }
 }
 
-impl<'a, H, Tail> ToMut<'a> for HCons<H, Tail>
+impl<A, B, Tail> HUnzippable for HCons<(A, B), Tail>
 where
-    H: 'a,
-    Tail: ToMut<'a>,
+    Tail: HUnzippable,
 {
-    type Output = HCons<&'a mut H, <Tail as ToMut<'a>>::Output>;
+    type Left = HCons<A, <Tail as HUnzippable>::Left>;
+    type Right = HCons<B, <Tail as HUnzippable>::Right>;
 
-    #[inline(always)]
-    fn to_mut(&'a mut self) -> Self::Output {
-        HCons {
-            head: &mut self.head,
-            tail: self.tail.to_mut(),
-        }
+    fn unzip(self) -> (Self::Left, Self::Right) {
+        let HCons { head: (a, b), tail } = self;
+        let (left_tail, right_tail) = tail.unzip();
+        (
+            HCons {
+                head: a,
+                tail: left_tail,
+            },
+            HCons {
+                head: b,
+                tail: right_tail,
+            },
+        )
     }
 }
 
-/// Trait for performing a left fold over an HList
+/// Trait for combining two HLists element-wise
 ///
 /// This trait is part of the implementation of the inherent method
-/// [`HCons::foldl`]. Please see that method for more information.
+/// [`HCons::zip_with`]. Please see that method for more information.
 ///
 /// You only need to import this trait when working with generic
-/// HLists or Mappers of unknown type. If the type of everything is known,
-/// then `list.foldl(f, acc)` should "just work" even without the trait.
+/// HLists or Zippers of unknown type. If the type of everything is known,
+/// then `list.zip_with(list2, f)` should "just work" even without the trait.
 ///
-/// [`HCons::foldl`]: struct.HCons.html#method.foldl
-pub trait HFoldLeftable<Folder, Acc> {
+/// [`HCons::zip_with`]: struct.HCons.html#method.zip_with
+pub trait HZipWithable<Other, Zipper> {
     type Output;
 
-    /// Perform a left fold over an HList.
+    /// Combine two HLists element-wise.
     ///
     /// Please see the [inherent method] for more information.
     ///
@@ -1102,53 +3906,45 @@ pub trait HFoldLeftable<Folder, Acc> {
     /// trait method is the location of the type parameters.
     /// (here, they are on the trait rather than the method)
     ///
-    /// [inherent method]: struct.HCons.html#method.foldl
-    fn foldl(self, folder: Folder, acc: Acc) -> Self::Output;
+    /// [inherent method]: struct.HCons.html#method.zip_with
+    fn zip_with(self, other: Other, zipper: Zipper) -> Self::Output;
 }
 
-impl<F, Acc> HFoldLeftable<F, Acc> for HNil {
-    type Output = Acc;
+impl<F> HZipWithable<HNil, F> for HNil {
+    type Output = HNil;
 
-    fn foldl(self, _: F, acc: Acc) -> Self::Output {
-        acc
+    fn zip_with(self, _other: HNil, _: F) -> Self::Output {
+        HNil
     }
 }
 
-impl<F, R, FTail, H, Tail, Acc> HFoldLeftable<HCons<F, FTail>, Acc> for HCons<H, Tail>
+impl<F, R, FTail, H1, T1, H2, T2> HZipWithable<HCons<H2, T2>, HCons<F, FTail>> for HCons<H1, T1>
 where
-    Tail: HFoldLeftable<FTail, R>,
-    F: FnOnce(Acc, H) -> R,
+    F: FnOnce(H1, H2) -> R,
+    T1: HZipWithable<T2, FTail>,
 {
-    type Output = <Tail as HFoldLeftable<FTail, R>>::Output;
+    type Output = HCons<R, <T1 as HZipWithable<T2, FTail>>::Output>;
 
-    fn foldl(self, folder: HCons<F, FTail>, acc: Acc) -> Self::Output {
-        let HCons { head, tail } = self;
-        tail.foldl(folder.tail, (folder.head)(acc, head))
+    fn zip_with(self, other: HCons<H2, T2>, zipper: HCons<F, FTail>) -> Self::Output {
+        HCons {
+            head: (zipper.head)(self.head, other.head),
+            tail: self.tail.zip_with(other.tail, zipper.tail),
+        }
     }
 }
 
-/// Implementation for folding over an HList using a single function that
-/// can handle all cases
-///
-/// ```
-/// # #[macro_use] extern crate frunk; fn main() {
-/// let h = hlist![1, 2, 3, 4, 5];
-///
-/// let r: isize = h.foldl(|acc, next| acc + next, 0);
-/// assert_eq!(r, 15);
-/// # }
-/// ```
-impl<F, H, Tail, Acc> HFoldLeftable<F, Acc> for HCons<H, Tail>
+impl<P, H1, T1, H2, T2> HZipWithable<HCons<H2, T2>, Poly<P>> for HCons<H1, T1>
 where
-    Tail: HFoldLeftable<F, Acc>,
-    F: Fn(Acc, H) -> Acc,
+    P: Func<(H1, H2)>,
+    T1: HZipWithable<T2, Poly<P>>,
 {
-    type Output = <Tail as HFoldLeftable<F, Acc>>::Output;
+    type Output = HCons<<P as Func<(H1, H2)>>::Output, <T1 as HZipWithable<T2, Poly<P>>>::Output>;
 
-    fn foldl(self, f: F, acc: Acc) -> Self::Output {
-        let HCons { head, tail } = self;
-        let acc = f(acc, head);
-        tail.foldl(f, acc)
+    fn zip_with(self, other: HCons<H2, T2>, poly: Poly<P>) -> Self::Output {
+        HCons {
+            head: P::call((self.head, other.head)),
+            tail: self.tail.zip_with(other.tail, poly),
+        }
     }
 }
 
@@ -1219,10 +4015,344 @@ where
     }
 }
 
-#[cfg(feature = "std")]
-impl<T> Into<Vec<T>> for HNil {
-    fn into(self) -> Vec<T> {
-        Vec::with_capacity(0)
+#[cfg(feature = "std")]
+impl<T> Into<Vec<T>> for HNil {
+    fn into(self) -> Vec<T> {
+        Vec::with_capacity(0)
+    }
+}
+
+/// Error returned when building an HList from a collection of the wrong length.
+#[derive(PartialEq, Debug, Eq, Clone, Copy, Hash)]
+pub struct LengthMismatch {
+    /// The length of the HList.
+    pub expected: usize,
+    /// The number of elements that were provided, or `None` if there were
+    /// more than `expected` and they were not counted.
+    pub actual: Option<usize>,
+}
+
+impl fmt::Display for LengthMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.actual {
+            Some(actual) => write!(
+                f,
+                "expected {} elements for the HList, found {}",
+                self.expected, actual
+            ),
+            None => write!(
+                f,
+                "expected {} elements for the HList, found more",
+                self.expected
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for LengthMismatch {}
+
+/// Trait for building a homogeneous HList from an iterator
+///
+/// This trait is part of the implementation of the inherent static method
+/// [`HCons::try_from_iter`]. Please see that method for more information.
+///
+/// You only need to import this trait when working with generic
+/// HLists of unknown type. If the type of everything is known,
+/// then `<Hlist![T, T]>::try_from_iter(iter)` should "just work" even without
+/// the trait.
+///
+/// [`HCons::try_from_iter`]: struct.HCons.html#method.try_from_iter
+pub trait HTryFromIterator<T>: HList {
+    /// Build a homogeneous HList from the elements of an iterator.
+    ///
+    /// Please see the [inherent method] for more information.
+    ///
+    /// [inherent method]: struct.HCons.html#method.try_from_iter
+    fn try_from_iter<I>(iter: I) -> Result<Self, LengthMismatch>
+    where
+        I: IntoIterator<Item = T>;
+}
+
+impl<T> HTryFromIterator<T> for HNil {
+    fn try_from_iter<I>(iter: I) -> Result<Self, LengthMismatch>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        match iter.into_iter().next() {
+            None => Ok(HNil),
+            Some(_) => Err(LengthMismatch {
+                expected: 0,
+                actual: None,
+            }),
+        }
+    }
+}
+
+impl<T, Tail> HTryFromIterator<T> for HCons<T, Tail>
+where
+    Tail: HTryFromIterator<T>,
+{
+    fn try_from_iter<I>(iter: I) -> Result<Self, LengthMismatch>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut iter = iter.into_iter();
+        match iter.next() {
+            Some(head) => match Tail::try_from_iter(&mut iter) {
+                Ok(tail) => Ok(HCons { head, tail }),
+                Err(e) => Err(LengthMismatch {
+                    expected: e.expected + 1,
+                    actual: e.actual.map(|actual| actual + 1),
+                }),
+            },
+            None => Err(LengthMismatch {
+                expected: Self::LEN,
+                actual: Some(0),
+            }),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> TryFrom<Vec<T>> for HNil {
+    type Error = LengthMismatch;
+
+    fn try_from(v: Vec<T>) -> Result<Self, Self::Error> {
+        HTryFromIterator::try_from_iter(v)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T, Tail> TryFrom<Vec<T>> for HCons<T, Tail>
+where
+    Tail: HTryFromIterator<T>,
+{
+    type Error = LengthMismatch;
+
+    fn try_from(v: Vec<T>) -> Result<Self, Self::Error> {
+        if v.len() != Self::LEN {
+            return Err(LengthMismatch {
+                expected: Self::LEN,
+                actual: Some(v.len()),
+            });
+        }
+        HTryFromIterator::try_from_iter(v)
+    }
+}
+
+/// Generates conversions between arrays and homogeneous HLists of the
+/// same length, one pair of impls per length.
+macro_rules! array_hlist_impls {
+    ([$($done:ident)*]) => {
+        array_hlist_impls!(@impl $($done)*);
+    };
+    ([$($done:ident)*] $next:ident $($rest:ident)*) => {
+        array_hlist_impls!(@impl $($done)*);
+        array_hlist_impls!([$($done)* $next] $($rest)*);
+    };
+    (@impl $($x:ident)*) => {
+        impl<T> From<[T; 0 $(+ array_hlist_impls!(@one $x))*]>
+            for Hlist![$(array_hlist_impls!(@elem T $x)),*]
+        {
+            fn from(array: [T; 0 $(+ array_hlist_impls!(@one $x))*]) -> Self {
+                let [$($x),*] = array;
+                hlist![$($x),*]
+            }
+        }
+
+        impl<T> From<Hlist![$(array_hlist_impls!(@elem T $x)),*]>
+            for [T; 0 $(+ array_hlist_impls!(@one $x))*]
+        {
+            fn from(h: Hlist![$(array_hlist_impls!(@elem T $x)),*]) -> Self {
+                let hlist_pat![$($x),*] = h;
+                [$($x),*]
+            }
+        }
+    };
+    (@one $x:ident) => { 1 };
+    (@elem $t:ident $x:ident) => { $t };
+}
+
+array_hlist_impls!([] a0 a1 a2 a3 a4 a5 a6 a7 a8 a9 a10 a11 a12 a13 a14 a15 a16 a17 a18 a19 a20 a21 a22 a23 a24 a25 a26 a27 a28 a29 a30 a31);
+
+/// Trait for functions that can be called with an HList of arguments
+///
+/// This is implemented for everything implementing `FnOnce` with up to 24
+/// parameters, where `Args` is the HList of the parameter types. It backs
+/// the inherent method [`HCons::apply`]; see also [`HApply`], which is the
+/// same thing seen from the HList's side.
+///
+/// [`HCons::apply`]: struct.HCons.html#method.apply
+/// [`HApply`]: trait.HApply.html
+pub trait FnApply<Args> {
+    type Output;
+
+    /// Call the function with the elements of `args` as its arguments.
+    fn apply(self, args: Args) -> Self::Output;
+}
+
+/// Trait for HLists that can be used as the arguments of a function
+///
+/// This trait is part of the implementation of the inherent method
+/// [`HCons::apply`]. Please see that method for more information.
+///
+/// You only need to import this trait when working with generic
+/// HLists or functions of unknown type. If the type of everything is known,
+/// then `list.apply(f)` should "just work" even without the trait.
+///
+/// [`HCons::apply`]: struct.HCons.html#method.apply
+pub trait HApply<F> {
+    type Output;
+
+    /// Call a function, using the elements of the HList as its arguments.
+    ///
+    /// Please see the [inherent method] for more information.
+    ///
+    /// [inherent method]: struct.HCons.html#method.apply
+    fn apply(self, f: F) -> Self::Output;
+}
+
+impl<Args, F> HApply<F> for Args
+where
+    Args: HList,
+    F: FnApply<Args>,
+{
+    type Output = <F as FnApply<Args>>::Output;
+
+    fn apply(self, f: F) -> Self::Output {
+        f.apply(self)
+    }
+}
+
+/// Trait for functions of an HList that can be turned into functions of
+/// the individual elements
+///
+/// This is implemented for everything implementing `Fn` with a single HList
+/// parameter of up to 24 elements. It backs the free function [`curry`].
+///
+/// [`curry`]: fn.curry.html
+#[cfg(feature = "std")]
+pub trait FnCurry<'a, Args> {
+    /// The curried function, which takes one parameter per element of `Args`
+    type Curried;
+
+    /// Turn a function of an HList into a function of its elements.
+    fn curry(self) -> Self::Curried;
+}
+
+/// Turn a function taking an HList into a function taking one argument per
+/// element of that HList.
+///
+/// This is the opposite of [`apply`]. Since the arity of the resulting
+/// function depends on the HList, it is returned as a boxed trait object.
+///
+/// [`apply`]: struct.HCons.html#method.apply
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate frunk; fn main() {
+/// use frunk::hlist::curry;
+///
+/// let describe = |h: Hlist![i32, &'static str]| format!("{} {}", h.head, h.tail.head);
+///
+/// let curried = curry(describe);
+/// assert_eq!(curried(1, "joe"), "1 joe");
+/// assert_eq!(curried(2, "schmoe"), "2 schmoe");
+/// # }
+/// ```
+#[cfg(feature = "std")]
+pub fn curry<'a, Args, F: FnCurry<'a, Args>>(f: F) -> F::Curried {
+    f.curry()
+}
+
+/// Generates the `FnApply` and `FnCurry` impls, one per arity.
+macro_rules! fn_hlist_impls {
+    ([$($done:ident)*]) => {
+        fn_hlist_impls!(@impl $($done)*);
+    };
+    ([$($done:ident)*] $next:ident $($rest:ident)*) => {
+        fn_hlist_impls!(@impl $($done)*);
+        fn_hlist_impls!([$($done)* $next] $($rest)*);
+    };
+    (@impl $($arg:ident)*) => {
+        impl<F, R, $($arg),*> FnApply<Hlist![$($arg),*]> for F
+        where
+            F: FnOnce($($arg),*) -> R,
+        {
+            type Output = R;
+
+            #[allow(non_snake_case)]
+            fn apply(self, args: Hlist![$($arg),*]) -> R {
+                let hlist_pat![$($arg),*] = args;
+                self($($arg),*)
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl<'a, F, R, $($arg),*> FnCurry<'a, Hlist![$($arg),*]> for F
+        where
+            F: Fn(Hlist![$($arg),*]) -> R + 'a,
+        {
+            type Curried = Box<dyn Fn($($arg),*) -> R + 'a>;
+
+            #[allow(non_snake_case)]
+            fn curry(self) -> Self::Curried {
+                Box::new(move |$($arg),*| self(hlist![$($arg),*]))
+            }
+        }
+    };
+}
+
+fn_hlist_impls!([] A0 A1 A2 A3 A4 A5 A6 A7 A8 A9 A10 A11 A12 A13 A14 A15 A16 A17 A18 A19 A20 A21 A22 A23);
+
+impl Extend<HNil> for HNil {
+    fn extend<I: IntoIterator<Item = HNil>>(&mut self, iter: I) {
+        iter.into_iter().for_each(drop)
+    }
+}
+
+/// Extends an HList of collections with rows, pushing the elements of each
+/// row into the corresponding collection.
+///
+/// ```
+/// # #[macro_use] extern crate frunk; fn main() {
+/// let mut columns = hlist![vec![1], vec!["a"]];
+/// columns.extend(vec![hlist![2, "b"], hlist![3, "c"]]);
+///
+/// assert_eq!(columns, hlist![vec![1, 2, 3], vec!["a", "b", "c"]]);
+/// # }
+/// ```
+impl<H, Tail, EH, ETail> Extend<HCons<EH, ETail>> for HCons<H, Tail>
+where
+    H: Extend<EH>,
+    Tail: Extend<ETail>,
+{
+    fn extend<I: IntoIterator<Item = HCons<EH, ETail>>>(&mut self, iter: I) {
+        for row in iter {
+            self.head.extend(iter::once(row.head));
+            self.tail.extend(iter::once(row.tail));
+        }
+    }
+}
+
+impl FromIterator<HNil> for HNil {
+    fn from_iter<I: IntoIterator<Item = HNil>>(iter: I) -> Self {
+        let mut nil = HNil;
+        nil.extend(iter);
+        nil
+    }
+}
+
+impl<H, Tail, EH, ETail> FromIterator<HCons<EH, ETail>> for HCons<H, Tail>
+where
+    Self: Default + Extend<HCons<EH, ETail>>,
+{
+    fn from_iter<I: IntoIterator<Item = HCons<EH, ETail>>>(iter: I) -> Self {
+        let mut columns = Self::default();
+        columns.extend(iter);
+        columns
     }
 }
 
@@ -1500,6 +4630,745 @@ mod tests {
         assert_eq!((&h.head), &1);
     }
 
+    #[test]
+    fn test_poly_foldl() {
+        struct P;
+        impl Func<(usize, i32)> for P {
+            type Output = usize;
+            fn call((acc, _): (usize, i32)) -> Self::Output {
+                acc + 4
+            }
+        }
+        impl<'a> Func<(usize, &'a str)> for P {
+            type Output = usize;
+            fn call((acc, s): (usize, &'a str)) -> Self::Output {
+                acc + s.len()
+            }
+        }
+        impl Func<(usize, bool)> for P {
+            type Output = bool;
+            fn call((acc, b): (usize, bool)) -> Self::Output {
+                b && acc > 10
+            }
+        }
+        let h = hlist![9000, "joe", 41, "schmoe"];
+        assert_eq!(h.foldl(Poly(P), 0), 17);
+        let h = hlist![9000, "joe", true];
+        assert!(h.foldl(Poly(P), 5));
+        assert_eq!(HNil.foldl(Poly(P), 3), 3);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_poly_foldr() {
+        struct P;
+        impl Func<(i32, Vec<i32>)> for P {
+            type Output = Vec<i32>;
+            fn call((i, mut acc): (i32, Vec<i32>)) -> Self::Output {
+                acc.push(i);
+                acc
+            }
+        }
+        impl<'a> Func<(&'a str, Vec<i32>)> for P {
+            type Output = Vec<i32>;
+            fn call((s, mut acc): (&'a str, Vec<i32>)) -> Self::Output {
+                acc.push(s.len() as i32);
+                acc
+            }
+        }
+        let h = hlist![1, "joe", 2, "schmoe"];
+        assert_eq!(h.foldr(Poly(P), Vec::new()), vec![6, 2, 3, 1]);
+    }
+
+    #[test]
+    fn test_poly_mut_map_and_folds() {
+        struct Counter(usize);
+        impl FuncMut<i32> for Counter {
+            type Output = (usize, i32);
+            fn call_mut(&mut self, i: i32) -> Self::Output {
+                self.0 += 1;
+                (self.0, i)
+            }
+        }
+        impl<'a> FuncMut<&'a str> for Counter {
+            type Output = (usize, usize);
+            fn call_mut(&mut self, s: &'a str) -> Self::Output {
+                self.0 += 1;
+                (self.0, s.len())
+            }
+        }
+        impl FuncMut<(usize, i32)> for Counter {
+            type Output = usize;
+            fn call_mut(&mut self, (acc, i): (usize, i32)) -> Self::Output {
+                self.0 += 1;
+                acc * 10 + i as usize
+            }
+        }
+        impl FuncMut<(i32, usize)> for Counter {
+            type Output = usize;
+            fn call_mut(&mut self, (i, acc): (i32, usize)) -> Self::Output {
+                self.0 += 1;
+                acc * 10 + i as usize
+            }
+        }
+
+        let mut counter = Counter(0);
+        let mapped = hlist![9, "joe", 8].map(PolyMut(&mut counter));
+        assert_eq!(mapped, hlist![(1, 9), (2, 3), (3, 8)]);
+        assert_eq!(counter.0, 3);
+
+        let folded = hlist![1, 2, 3].foldl(PolyMut(&mut counter), 0usize);
+        assert_eq!(folded, 123);
+        assert_eq!(counter.0, 6);
+
+        let folded = hlist![1, 2, 3].foldr(PolyMut(&mut counter), 0usize);
+        assert_eq!(folded, 321);
+        assert_eq!(counter.0, 9);
+
+        assert_eq!(HNil.foldr(PolyMut(Counter(0)), 7), 7);
+    }
+
+    #[test]
+    fn test_poly_ref_map_and_folds() {
+        struct Base(usize);
+        impl FuncRef<i32> for Base {
+            type Output = usize;
+            fn call_ref(&self, i: i32) -> Self::Output {
+                i as usize * self.0
+            }
+        }
+        impl<'a> FuncRef<&'a str> for Base {
+            type Output = usize;
+            fn call_ref(&self, s: &'a str) -> Self::Output {
+                s.len() * self.0
+            }
+        }
+        impl FuncRef<(usize, i32)> for Base {
+            type Output = usize;
+            fn call_ref(&self, (acc, i): (usize, i32)) -> Self::Output {
+                acc * self.0 + i as usize
+            }
+        }
+        impl FuncRef<(i32, usize)> for Base {
+            type Output = usize;
+            fn call_ref(&self, (i, acc): (i32, usize)) -> Self::Output {
+                acc * self.0 + i as usize
+            }
+        }
+
+        let base = Base(10);
+        let shared = &base;
+
+        let mapped = hlist![9, "joe", 8].map(PolyRef(&base));
+        assert_eq!(mapped, hlist![90, 30, 80]);
+
+        let folded = hlist![1, 2, 3].foldl(PolyRef(shared), 0usize);
+        assert_eq!(folded, 123);
+
+        let folded = hlist![1, 2, 3].foldr(PolyRef(&base), 0usize);
+        assert_eq!(folded, 321);
+
+        assert_eq!(HNil.foldr(PolyRef(Base(10)), 7), 7);
+        assert_eq!(shared.0, 10);
+    }
+
+    #[test]
+    fn test_for_each() {
+        let mut acc = 0;
+        hlist![1, 2, 3].for_each(|i| acc += i);
+        assert_eq!(acc, 6);
+
+        let total_len = ::std::cell::Cell::new(0);
+        hlist![9000, "joe", true].to_ref().for_each(hlist![
+            |_: &i32| total_len.set(total_len.get() + 4),
+            |s: &&str| total_len.set(total_len.get() + s.len()),
+            |_: &bool| total_len.set(total_len.get() + 1),
+        ]);
+        assert_eq!(total_len.get(), 8);
+
+        HNil.for_each(|_: i32| unreachable!());
+    }
+
+    #[test]
+    fn test_poly_for_each() {
+        struct Register(usize);
+        impl<'a> FuncMut<&'a i32> for Register {
+            type Output = ();
+            fn call_mut(&mut self, _: &'a i32) {
+                self.0 += 1;
+            }
+        }
+        impl<'a> FuncMut<&'a f32> for Register {
+            type Output = ();
+            fn call_mut(&mut self, _: &'a f32) {
+                self.0 += 10;
+            }
+        }
+        let h = hlist![1, 2f32, 3];
+        let mut register = Register(0);
+        h.to_ref().for_each(PolyMut(&mut register));
+        assert_eq!(register.0, 12);
+
+        struct Check;
+        impl Func<i32> for Check {
+            type Output = ();
+            fn call(i: i32) {
+                assert!(i > 0);
+            }
+        }
+        hlist![1, 2].for_each(Poly(Check));
+
+        struct AtMost(i32, ::std::cell::Cell<usize>);
+        impl FuncRef<i32> for AtMost {
+            type Output = ();
+            fn call_ref(&self, i: i32) {
+                assert!(i <= self.0);
+                self.1.set(self.1.get() + 1);
+            }
+        }
+        let at_most = AtMost(3, ::std::cell::Cell::new(0));
+        hlist![1, 2, 3].for_each(PolyRef(&at_most));
+        assert_eq!(at_most.1.get(), 3);
+    }
+
+    #[test]
+    fn test_for_each_mut() {
+        let mut h = hlist![1, 2f32, 3];
+        h.for_each_mut(hlist![
+            |i: &mut i32| *i *= 2,
+            |f: &mut f32| *f += 0.5,
+            |i: &mut i32| *i = -*i
+        ]);
+        assert_eq!(h, hlist![2, 2.5f32, -3]);
+
+        let mut h = hlist![1, 2, 3];
+        h.for_each_mut(|i: &mut i32| *i += 1);
+        assert_eq!(h, hlist![2, 3, 4]);
+    }
+
+    #[test]
+    fn test_sequence() {
+        assert_eq!(hlist![Some(1)].sequence(), Some(hlist![1]));
+        assert_eq!(
+            hlist![Some(1), Some("joe"), Some(2f32)].sequence(),
+            Some(hlist![1, "joe", 2f32])
+        );
+        assert_eq!(hlist![Some(1), None::<bool>].sequence(), None);
+
+        let h: Hlist![Result<i32, &str>, Result<bool, &str>] = hlist![Ok(1), Ok(true)];
+        assert_eq!(h.sequence(), Ok(hlist![1, true]));
+        let h: Hlist![Result<i32, &str>, Result<bool, &str>] = hlist![Ok(1), Err("nope")];
+        assert_eq!(h.sequence(), Err("nope"));
+    }
+
+    #[test]
+    fn test_try_map() {
+        let h = hlist![1, 2, 3];
+        let r: Result<_, ()> = h.try_map(|n: i32| Ok(n * 2));
+        assert_eq!(r, Ok(hlist![2, 4, 6]));
+
+        let h = hlist![1, "joe", 3];
+        let r = h.try_map(hlist![
+            |n: i32| Ok(n + 1),
+            |s: &str| if s.is_empty() { Ok(true) } else { Err(s.len()) },
+            |_: i32| -> Result<i32, usize> { panic!("should short-circuit") },
+        ]);
+        assert_eq!(r, Err(3));
+
+        struct P;
+        impl Func<i32> for P {
+            type Output = Result<bool, u8>;
+            fn call(n: i32) -> Self::Output {
+                if n > 0 {
+                    Ok(n > 100)
+                } else {
+                    Err(0)
+                }
+            }
+        }
+        impl Func<bool> for P {
+            type Output = Result<u8, u8>;
+            fn call(b: bool) -> Self::Output {
+                Ok(b as u8)
+            }
+        }
+        assert_eq!(hlist![9000, true].try_map(Poly(P)), Ok(hlist![true, 1]));
+        assert_eq!(hlist![-1, true].try_map(Poly(P)), Err(0));
+    }
+
+    #[test]
+    fn test_try_foldl() {
+        let h = hlist![1, 2, 3];
+        let r: Result<i32, ()> = h.try_foldl(|acc: i32, n: i32| Ok(acc + n), 0);
+        assert_eq!(r, Ok(6));
+
+        let h = hlist![1, 0, 3];
+        let r = h.try_foldl(
+            |acc: i32, n: i32| acc.checked_div(n).ok_or("division by zero"),
+            100,
+        );
+        assert_eq!(r, Err("division by zero"));
+
+        let h = hlist![1, "joe"];
+        let r: Result<usize, ()> = h.try_foldl(
+            hlist![
+                |acc: usize, n: i32| Ok(acc + n as usize),
+                |acc, s: &str| Ok(acc + s.len())
+            ],
+            0,
+        );
+        assert_eq!(r, Ok(4));
+
+        struct P;
+        impl Func<(usize, i32)> for P {
+            type Output = Result<usize, i32>;
+            fn call((acc, n): (usize, i32)) -> Self::Output {
+                if n >= 0 {
+                    Ok(acc + n as usize)
+                } else {
+                    Err(n)
+                }
+            }
+        }
+        impl<'a> Func<(usize, &'a str)> for P {
+            type Output = Result<usize, i32>;
+            fn call((acc, s): (usize, &'a str)) -> Self::Output {
+                Ok(acc + s.len())
+            }
+        }
+        assert_eq!(hlist![1, "joe", 2].try_foldl(Poly(P), 0), Ok(6));
+        assert_eq!(hlist![1, "joe", -2].try_foldl(Poly(P), 0), Err(-2));
+    }
+
+    #[test]
+    fn test_positional_indexing() {
+        use nat::{N0, N1, N2, N3};
+
+        let mut h = hlist!["a", 1, "b", 2.0];
+        assert_eq!(*h.get_at::<N0>(), "a");
+        assert_eq!(*h.get_at::<N2>(), "b");
+        *h.get_at_mut::<N1>() += 1;
+        assert_eq!(*h.get_at::<N1>(), 2);
+
+        let (b, rest) = h.pluck_at::<N2>();
+        assert_eq!(b, "b");
+        assert_eq!(rest, hlist!["a", 2, 2.0]);
+
+        assert_eq!(h.split_at::<N0>(), (HNil, h));
+        assert_eq!(h.split_at::<N1>(), (hlist!["a"], hlist![2, "b", 2.0]));
+        assert_eq!(h.take::<N3>(), hlist!["a", 2, "b"]);
+        assert_eq!(h.drop::<N3>(), hlist![2.0]);
+        assert_eq!(h.drop::<There<N3>>(), HNil);
+        assert_eq!(HNil.split_at::<N0>(), (HNil, HNil));
+    }
+
+    #[test]
+    fn test_nat_values() {
+        use nat::{Nat, N0, N31, N7};
+
+        assert_eq!(N0::VALUE, 0);
+        assert_eq!(N7::VALUE, 7);
+        assert_eq!(N31::VALUE, 31);
+        assert_eq!(<There<N31> as Nat>::VALUE, 32);
+    }
+
+    #[test]
+    fn test_len_const_generic() {
+        fn len_of<L: HListLen<N>, const N: usize>(_: &L) -> usize {
+            N
+        }
+        fn array_for<L: HListLen<N>, const N: usize>() -> [usize; N] {
+            [L::LEN; N]
+        }
+
+        assert_eq!(len_of(&HNil), 0);
+        assert_eq!(len_of(&hlist![1, "joe", 2f32]), 3);
+        assert_eq!(array_for::<Hlist![i32, bool], 2>(), [2, 2]);
+
+        let buffer = [0u8; <Hlist![i32, bool, f32] as HList>::LEN];
+        assert_eq!(buffer.len(), 3);
+    }
+
+    #[test]
+    fn test_array_conversions() {
+        let h: Hlist![i32, i32, i32] = [1, 2, 3].into();
+        assert_eq!(h, hlist![1, 2, 3]);
+        let a: [i32; 3] = h.into();
+        assert_eq!(a, [1, 2, 3]);
+
+        let nil: HNil = <[u8; 0]>::into([]);
+        assert_eq!(nil, HNil);
+        let empty: [u8; 0] = HNil.into();
+        assert_eq!(empty, []);
+
+        let h = <Hlist![
+            u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8,
+            u8, u8, u8, u8, u8, u8, u8, u8, u8, u8
+        ]>::from([7; 32]);
+        assert_eq!(h.head, 7);
+    }
+
+    #[test]
+    fn test_try_from_iter() {
+        let h = <Hlist![i32, i32]>::try_from_iter(1..3);
+        assert_eq!(h, Ok(hlist![1, 2]));
+        assert_eq!(
+            <Hlist![i32, i32]>::try_from_iter(Some(1)),
+            Err(LengthMismatch {
+                expected: 2,
+                actual: Some(1)
+            })
+        );
+        assert_eq!(
+            <Hlist![i32, i32]>::try_from_iter(0..5),
+            Err(LengthMismatch {
+                expected: 2,
+                actual: None
+            })
+        );
+        assert_eq!(HNil::try_from_iter(None::<i32>), Ok(HNil));
+        assert_eq!(
+            HNil::try_from_iter(Some(1)),
+            Err(LengthMismatch {
+                expected: 0,
+                actual: None
+            })
+        );
+
+        // Only one element past the end is pulled, so this returns
+        let mut naturals = 0u8..;
+        assert_eq!(
+            <Hlist![u8, u8]>::try_from_iter(&mut naturals),
+            Err(LengthMismatch {
+                expected: 2,
+                actual: None
+            })
+        );
+        assert_eq!(naturals.next(), Some(3));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_try_from_vec() {
+        let h = <Hlist![String, String]>::try_from(vec!["a".to_owned(), "b".to_owned()]);
+        assert_eq!(h, Ok(hlist!["a".to_owned(), "b".to_owned()]));
+        let h = <Hlist![i32, i32]>::try_from(vec![1, 2, 3]);
+        assert_eq!(
+            h,
+            Err(LengthMismatch {
+                expected: 2,
+                actual: Some(3)
+            })
+        );
+        assert_eq!(HNil::try_from(Vec::<i32>::new()), Ok(HNil));
+        assert_eq!(
+            h.unwrap_err().to_string(),
+            "expected 2 elements for the HList, found 3"
+        );
+        assert_eq!(
+            <Hlist![i32, i32]>::try_from_iter(0..)
+                .unwrap_err()
+                .to_string(),
+            "expected 2 elements for the HList, found more"
+        );
+
+        let v: Vec<i32> = hlist![1, 2, 3].into();
+        assert_eq!(<Hlist![i32, i32, i32]>::try_from(v), Ok(hlist![1, 2, 3]));
+    }
+
+    #[test]
+    fn test_push_and_pop_back() {
+        let h = HNil.push_back(1).push_back("joe").push_back(2f32);
+        assert_eq!(h, hlist![1, "joe", 2f32]);
+
+        let (last, init) = h.pop_back();
+        assert_eq!(last, 2f32);
+        assert_eq!(init, hlist![1, "joe"]);
+        assert_eq!(hlist![true].pop_back(), (true, HNil));
+
+        assert_eq!(h.last(), 2f32);
+        assert_eq!(h.init(), hlist![1, "joe"]);
+        assert_eq!(hlist![1].init(), HNil);
+        assert_eq!(h.init().push_back('x').last(), 'x');
+
+        let mut h = hlist![1, 2];
+        *h.to_mut().last() += 40;
+        assert_eq!(h, hlist![1, 42]);
+    }
+
+    #[test]
+    fn test_apply() {
+        fn add3(a: i32, b: i32, c: i32) -> i32 {
+            a + b + c
+        }
+        assert_eq!(hlist![1, 2, 3].apply(add3), 6);
+        assert_eq!(hlist!["joe"].apply(str::len), 3);
+        assert_eq!(HNil.apply(|| "nothing"), "nothing");
+
+        let mut calls = 0;
+        hlist![1, true].apply(|i: i32, b: bool| {
+            if b {
+                calls += i
+            }
+        });
+        assert_eq!(calls, 1);
+
+        let h = hlist![
+            0u8, 1u8, 2u8, 3u8, 4u8, 5u8, 6u8, 7u8, 8u8, 9u8, 10u8, 11u8, 12u8, 13u8, 14u8, 15u8,
+            16u8, 17u8, 18u8, 19u8, 20u8, 21u8, 22u8, 23u8
+        ];
+        let last = h.apply(
+            |_: u8,
+             _: u8,
+             _: u8,
+             _: u8,
+             _: u8,
+             _: u8,
+             _: u8,
+             _: u8,
+             _: u8,
+             _: u8,
+             _: u8,
+             _: u8,
+             _: u8,
+             _: u8,
+             _: u8,
+             _: u8,
+             _: u8,
+             _: u8,
+             _: u8,
+             _: u8,
+             _: u8,
+             _: u8,
+             _: u8,
+             x: u8| x,
+        );
+        assert_eq!(last, 23);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_curry() {
+        let sum = curry(|h: Hlist![i32, i32]| h.head + h.tail.head);
+        assert_eq!(sum(1, 2), 3);
+        assert_eq!(sum(40, 2), 42);
+
+        let nothing = curry(|_: HNil| 7);
+        assert_eq!(nothing(), 7);
+
+        let uncurried = |a: i32, b: i32| a * b;
+        let curried = curry(|h: Hlist![i32, i32]| h.apply(uncurried));
+        assert_eq!(curried(6, 7), 42);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_transpose() {
+        let columns = hlist![vec![1, 2, 3], vec!["a", "b"], 0..];
+        let mut rows = columns.transpose();
+        assert_eq!(rows.size_hint(), (2, Some(2)));
+        assert_eq!(rows.next(), Some(hlist![1, "a", 0]));
+        assert_eq!(rows.next(), Some(hlist![2, "b", 1]));
+        assert_eq!(rows.next(), None);
+
+        let rows = vec![hlist![1u64, 1.5f32], hlist![2, 2.5]];
+        let columns: Hlist![Vec<u64>, Vec<f32>] = rows.into_iter().collect();
+        assert_eq!(columns, hlist![vec![1, 2], vec![1.5, 2.5]]);
+
+        let back: Vec<_> = columns.transpose().collect();
+        assert_eq!(back, vec![hlist![1, 1.5], hlist![2, 2.5]]);
+    }
+
+    #[test]
+    fn test_extend_columns() {
+        let mut counts = hlist![0usize, 0usize];
+        struct Count<'a>(&'a mut usize);
+        impl<'a, T> Extend<T> for Count<'a> {
+            fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+                *self.0 += iter.into_iter().count();
+            }
+        }
+        {
+            let hlist_pat![a, b] = counts.to_mut();
+            let mut sinks = hlist![Count(a), Count(b)];
+            sinks.extend(Some(hlist![1, "x"]));
+            sinks.extend(Some(hlist![2, "y"]));
+        }
+        assert_eq!(counts, hlist![2, 2]);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_display_and_flat_debug() {
+        let h = hlist![1, "joe", 2.5f32, 'c'];
+        assert_eq!(h.to_string(), "[1, joe, 2.5, c]");
+        assert_eq!(format!("{:?}", h.flat_debug()), r#"[1, "joe", 2.5, 'c']"#);
+        assert_eq!(HNil.to_string(), "[]");
+        assert_eq!(format!("{:?}", HNil.flat_debug()), "[]");
+
+        let nested = hlist![hlist![1, 2], HNil];
+        assert_eq!(nested.to_string(), "[[1, 2], []]");
+        assert_eq!(format!("{:.1}", hlist![1.25f32]), "[1.2]");
+        assert_eq!(
+            format!("{:#?}", hlist![1, "a"].flat_debug()),
+            "[\n    1,\n    \"a\",\n]"
+        );
+    }
+
+    #[test]
+    fn test_union() {
+        let a = hlist![1, "joe"];
+        let b = hlist![true, "schmoe", 2f32];
+
+        let u: Hlist![i32, &str, bool, f32] = a.union(b);
+        assert_eq!(u, hlist![1, "joe", true, 2f32]);
+
+        let u: Hlist![bool, &str, f32, i32] = b.union(a);
+        assert_eq!(u, hlist![true, "schmoe", 2f32, 1]);
+
+        let u: Hlist![i32, &str] = a.union(HNil);
+        assert_eq!(u, a);
+        let u: Hlist![i32, &str] = HNil.union(a);
+        assert_eq!(u, a);
+        let u: Hlist![i32, &str] = a.union(hlist!["other", 3]);
+        assert_eq!(u, a);
+    }
+
+    #[test]
+    fn test_intersect_and_difference() {
+        let a = hlist![1, "joe", true, 2f32];
+        let b = hlist![false, 'c', 0];
+
+        let i: Hlist![i32, bool] = a.intersect(&b);
+        assert_eq!(i, hlist![1, true]);
+        let i: HNil = a.intersect(&HNil);
+        assert_eq!(i, HNil);
+
+        let d: Hlist![&str, f32] = a.difference(&b);
+        assert_eq!(d, hlist!["joe", 2f32]);
+        let d: Hlist![i32, &str, bool, f32] = a.difference(&HNil);
+        assert_eq!(d, a);
+        let d: HNil = a.difference(&hlist![2f32, true, "x", 9]);
+        assert_eq!(d, HNil);
+    }
+
+    #[test]
+    fn test_flatten() {
+        assert_eq!(HNil.flatten(), HNil);
+        assert_eq!(hlist![HNil, HNil].flatten(), HNil);
+        assert_eq!(
+            hlist![hlist![1, "joe"], HNil, hlist![2f32]].flatten(),
+            hlist![1, "joe", 2f32]
+        );
+        assert_eq!(
+            concat(hlist![hlist![1], hlist![hlist![2]]]),
+            hlist![1, hlist![2]]
+        );
+    }
+
+    #[test]
+    fn test_deep_flatten() {
+        let h = hlist![hlist![1, hlist!["joe", hlist![true]]], 2f32, HNil];
+
+        let flat: Hlist![i32, &str, bool, f32] = h.deep_flatten();
+        assert_eq!(flat, hlist![1, "joe", true, 2f32]);
+
+        let kept_nil =
+            hlist![HNil, 1].deep_flatten::<Hlist![HNil, i32], Hlist![FlattenLeaf, FlattenLeaf]>();
+        assert_eq!(kept_nil, hlist![HNil, 1]);
+
+        let same: Hlist![i32, bool] = hlist![1, true].deep_flatten();
+        assert_eq!(same, hlist![1, true]);
+    }
+
+    #[test]
+    fn test_type_names() {
+        assert_eq!(HNil::type_names(), HNil);
+        let hlist_pat![u8_name, str_name, hnil_name] = <Hlist![u8, &str, HNil]>::type_names();
+        assert_eq!((u8_name, str_name), ("u8", "&str"));
+        assert!(hnil_name.ends_with("HNil"));
+        let names: [&str; 2] = <Hlist![bool, f64]>::type_names().into();
+        assert_eq!(names, ["bool", "f64"]);
+    }
+
+    #[test]
+    fn test_scanl() {
+        assert_eq!(HNil.scanl(|acc: i32, n: i32| acc + n, 7), hlist![7]);
+
+        let h = hlist![1, 2, 3];
+        assert_eq!(h.scanl(|acc, n| acc * 10 + n, 0), hlist![0, 1, 12, 123]);
+
+        let h = hlist![2u8, 3i64];
+        let scanned = h.scanl(
+            hlist![
+                |acc: u16, n: u8| i32::from(acc) + i32::from(n),
+                |acc: i32, n: i64| { i64::from(acc) * n }
+            ],
+            1u16,
+        );
+        assert_eq!(scanned, hlist![1u16, 3i32, 9i64]);
+    }
+
+    #[test]
+    fn test_poly_scanl() {
+        struct Widen;
+        impl Func<(u8, u8)> for Widen {
+            type Output = u16;
+            fn call((acc, n): (u8, u8)) -> Self::Output {
+                u16::from(acc) + u16::from(n)
+            }
+        }
+        impl Func<(u16, u16)> for Widen {
+            type Output = u32;
+            fn call((acc, n): (u16, u16)) -> Self::Output {
+                u32::from(acc) + u32::from(n)
+            }
+        }
+
+        let h = hlist![200u8, 60000u16];
+        assert_eq!(h.scanl(Poly(Widen), 100u8), hlist![100u8, 300u16, 60300u32]);
+
+        struct Count(usize);
+        impl<T> FuncMut<(usize, T)> for Count {
+            type Output = usize;
+            fn call_mut(&mut self, (acc, _): (usize, T)) -> Self::Output {
+                self.0 += 1;
+                acc + self.0
+            }
+        }
+
+        let mut count = Count(0);
+        let h = hlist!["a", 'b', 3.0];
+        assert_eq!(h.scanl(PolyMut(&mut count), 0), hlist![0, 1, 3, 6]);
+        assert_eq!(count.0, 3);
+    }
+
+    #[test]
+    fn test_permute() {
+        use nat::{N0, N1, N2, N3};
+
+        assert_eq!(HNil.permute::<HNil>(), HNil);
+
+        let h = hlist!["a", 1, "b", 2];
+        let permuted = h.clone().permute::<Hlist![N3, N2, N1, N0]>();
+        assert_eq!(permuted, h.clone().into_reverse());
+
+        let permuted = h.clone().permute::<Hlist![N2, N3, N0, N1]>();
+        assert_eq!(permuted, hlist!["b", 2, "a", 1]);
+
+        assert_eq!(h.clone().permute::<Hlist![N0, N1, N2, N3]>(), h);
+    }
+
+    #[test]
+    fn test_sculpt_explicit_indices() {
+        use nat::{N0, N1};
+
+        type SecondFirst = Hlist![N1, N0];
+        let (swapped, rest) = hlist![1, 2, 3].sculpt::<Hlist![i32, i32], SecondFirst>();
+        assert_eq!(swapped, hlist![2, 1]);
+        assert_eq!(rest, hlist![3]);
+    }
+
     #[test]
     fn test_map_consuming() {
         let h = hlist![9000, "joe", 41f32];
@@ -1665,4 +5534,46 @@ mod tests {
         let x: H = hlist![(), 1337, 42.0, (), true].lift_into();
         assert_eq!(x, hlist![(), 1337, 42.0, (), true]);
     }
+
+    #[test]
+    fn test_zip_unzip() {
+        let h1 = hlist![1, false, "joe"];
+        let h2 = hlist![2u8, 42f32, Some(3)];
+        let zipped = h1.zip(h2);
+        assert_eq!(zipped, hlist![(1, 2u8), (false, 42f32), ("joe", Some(3))]);
+
+        let (u1, u2) = zipped.unzip();
+        assert_eq!(u1, h1);
+        assert_eq!(u2, h2);
+    }
+
+    #[test]
+    fn test_zip_with() {
+        let h1 = hlist![1, false, "joe"];
+        let h2 = hlist![2, true, 3];
+        let zipped = h1.zip_with(
+            h2,
+            hlist![|a, b| a + b, |a: bool, b| a || b, |s: &str, n| s.len() == n],
+        );
+        assert_eq!(zipped, hlist![3, true, true]);
+    }
+
+    #[test]
+    fn test_poly_zip_with() {
+        struct P;
+        impl Func<(i32, i32)> for P {
+            type Output = i32;
+            fn call((a, b): (i32, i32)) -> Self::Output {
+                a * b
+            }
+        }
+        impl<'a> Func<(&'a str, usize)> for P {
+            type Output = bool;
+            fn call((s, n): (&'a str, usize)) -> Self::Output {
+                s.len() == n
+            }
+        }
+        let zipped = hlist![3, "joe"].zip_with(hlist![4, 2], Poly(P));
+        assert_eq!(zipped, hlist![12, false]);
+    }
 }
@@ -3,13 +3,18 @@
 //! frunk frequently uses phantom index types as a technique to avoid
 //! overlapping impls for some traits.
 //!
-//! Currently, `Index` type parameters in traits are not ever really intended
+//! Most `Index` type parameters in traits are not ever really intended
 //! to be selected by the user, and are instead simply solved for by type
 //! inference wherever the compiler can see that there is a unique solution.
-//! Therefore, you don't really have much of a reason to use the things in this
-//! module.
 //!
-//! **...yet.** `;)`
+//! The exception is positional indexing of HLists (for instance with
+//! [`HCons::get_at`]), where [`Here`] and [`There`] are used as type-level
+//! natural numbers. The [`nat`] module provides readable aliases for those.
+//!
+//! [`HCons::get_at`]: ../hlist/struct.HCons.html#method.get_at
+//! [`Here`]: struct.Here.html
+//! [`There`]: struct.There.html
+//! [`nat`]: ../nat/index.html
 
 use std::marker::PhantomData;
 
@@ -56,3 +61,12 @@ pub struct PluckedLabelledGenericIndicesWrapper<T>(T);
 
 /// Index type wrapper for transmogrifying through a (known) container (e.g. `Vec`).
 pub struct MappingIndicesWrapper<T>(PhantomData<(T)>);
+
+/// Index for keeping an element as it is when deeply flattening an HList.
+pub enum FlattenLeaf {}
+
+/// Index for splicing in the (flattened) elements of a nested HList when deeply
+/// flattening an HList. `Mid` is the flattened form of the elements after it.
+pub struct FlattenNested<Mid, Indices> {
+    _marker: PhantomData<(Mid, Indices)>,
+}
@@ -69,6 +69,7 @@ pub mod generic;
 pub mod hlist;
 pub mod indices;
 pub mod labelled;
+pub mod nat;
 pub mod path;
 pub mod traits;
 mod tuples;
@@ -295,6 +295,80 @@ macro_rules! poly_fn {
     }}
 }
 
+/// Returns a polymorphic function that turns references into trait objects.
+///
+/// The function maps `&T` to `&dyn Trait` and `&mut T` to `&mut dyn Trait`
+/// for every `T` implementing the given trait. Mapping it over the output of
+/// `to_ref()` or `to_mut()` gives a homogeneous HList, which can then be
+/// turned into a `Vec` or an array and used with dynamic dispatch.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate frunk;
+/// # fn main() {
+/// use std::fmt::Debug;
+///
+/// let h = hlist![1, "joe", 42f32];
+///
+/// let v: Vec<&dyn Debug> = h.to_ref().map(poly_dyn!(Debug)).into();
+/// assert_eq!(format!("{:?}", v), r#"[1, "joe", 42.0]"#);
+///
+/// let a: [&dyn Debug; 3] = h.to_ref().map(poly_dyn!(Debug)).into();
+/// assert_eq!(format!("{:?}", a[1]), r#""joe""#);
+/// # }
+/// ```
+///
+/// Mutable references work the same way:
+///
+/// ```
+/// # #[macro_use] extern crate frunk;
+/// # fn main() {
+/// trait Plugin {
+///     fn start(&mut self);
+/// }
+///
+/// struct Logger(bool);
+/// struct Cache(u32);
+///
+/// impl Plugin for Logger {
+///     fn start(&mut self) { self.0 = true; }
+/// }
+/// impl Plugin for Cache {
+///     fn start(&mut self) { self.0 += 1; }
+/// }
+///
+/// let mut h = hlist![Logger(false), Cache(0)];
+/// let plugins: Vec<&mut dyn Plugin> = h.to_mut().map(poly_dyn!(Plugin)).into();
+/// for plugin in plugins {
+///     plugin.start();
+/// }
+/// assert!(h.head.0);
+/// assert_eq!(h.tail.head.0, 1);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! poly_dyn {
+    ($tr: path) => {{
+        struct F;
+        impl<'a, T: $tr + 'a> $crate::traits::Func<&'a T> for F {
+            type Output = &'a (dyn $tr + 'a);
+
+            fn call(t: &'a T) -> Self::Output {
+                t
+            }
+        }
+        impl<'a, T: $tr + 'a> $crate::traits::Func<&'a mut T> for F {
+            type Output = &'a mut (dyn $tr + 'a);
+
+            fn call(t: &'a mut T) -> Self::Output {
+                t
+            }
+        }
+        $crate::traits::Poly(F)
+    }};
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
@@ -392,4 +466,39 @@ mod tests {
         ));
         assert_eq!(h2, hlist![true, 3, "dummy", 6, false]);
     }
+
+    #[test]
+    fn poly_dyn_macro_test() {
+        trait Area {
+            fn area(&self) -> u32;
+            fn grow(&mut self);
+        }
+        struct Square(u32);
+        struct Rect(u32, u32);
+        impl Area for Square {
+            fn area(&self) -> u32 {
+                self.0 * self.0
+            }
+            fn grow(&mut self) {
+                self.0 += 1;
+            }
+        }
+        impl Area for Rect {
+            fn area(&self) -> u32 {
+                self.0 * self.1
+            }
+            fn grow(&mut self) {
+                self.1 += 1;
+            }
+        }
+
+        let mut h = hlist![Square(2), Rect(2, 3)];
+        let shapes: [&mut dyn Area; 2] = h.to_mut().map(poly_dyn!(Area)).into();
+        for shape in shapes {
+            shape.grow();
+        }
+        let shapes: [&dyn Area; 2] = h.to_ref().map(poly_dyn!(Area)).into();
+        let areas: [u32; 2] = [shapes[0].area(), shapes[1].area()];
+        assert_eq!(areas, [9, 8]);
+    }
 }
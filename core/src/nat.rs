@@ -0,0 +1,112 @@
+//! Type-level natural numbers, for indexing HLists by position.
+//!
+//! These are simply aliases for the [`Here`] and [`There`] index types, so
+//! that `N0` is `Here`, `N1` is `There<Here>`, and so on. They can be passed
+//! to positional methods such as [`HCons::get_at`] or [`HCons::split_at`]
+//! when selecting by type is ambiguous (e.g. for an HList containing two
+//! `String`s).
+//!
+//! ```
+//! # #[macro_use] extern crate frunk;
+//! # fn main() {
+//! use frunk::nat::{N1, N2, Nat};
+//!
+//! let h = hlist![String::from("first"), 1, String::from("second")];
+//!
+//! assert_eq!(h.get_at::<N2>(), "second");
+//! assert_eq!(N1::VALUE, 1);
+//! # }
+//! ```
+//!
+//! Larger numbers can be written out by hand as `There<...>` of a smaller one.
+//!
+//! [`Here`]: ../indices/struct.Here.html
+//! [`There`]: ../indices/struct.There.html
+//! [`HCons::get_at`]: ../hlist/struct.HCons.html#method.get_at
+//! [`HCons::split_at`]: ../hlist/struct.HCons.html#method.split_at
+
+use indices::{Here, There};
+
+/// A type-level natural number.
+///
+/// This is implemented for [`Here`] (zero) and for `There<N>` (one more than
+/// `N`), which makes the aliases in this module usable as values too.
+///
+/// [`Here`]: ../indices/struct.Here.html
+pub trait Nat {
+    /// The value of this number.
+    const VALUE: usize;
+}
+
+impl Nat for Here {
+    const VALUE: usize = 0;
+}
+
+impl<N: Nat> Nat for There<N> {
+    const VALUE: usize = N::VALUE + 1;
+}
+
+/// The type-level 0.
+pub type N0 = Here;
+/// The type-level 1.
+pub type N1 = There<N0>;
+/// The type-level 2.
+pub type N2 = There<N1>;
+/// The type-level 3.
+pub type N3 = There<N2>;
+/// The type-level 4.
+pub type N4 = There<N3>;
+/// The type-level 5.
+pub type N5 = There<N4>;
+/// The type-level 6.
+pub type N6 = There<N5>;
+/// The type-level 7.
+pub type N7 = There<N6>;
+/// The type-level 8.
+pub type N8 = There<N7>;
+/// The type-level 9.
+pub type N9 = There<N8>;
+/// The type-level 10.
+pub type N10 = There<N9>;
+/// The type-level 11.
+pub type N11 = There<N10>;
+/// The type-level 12.
+pub type N12 = There<N11>;
+/// The type-level 13.
+pub type N13 = There<N12>;
+/// The type-level 14.
+pub type N14 = There<N13>;
+/// The type-level 15.
+pub type N15 = There<N14>;
+/// The type-level 16.
+pub type N16 = There<N15>;
+/// The type-level 17.
+pub type N17 = There<N16>;
+/// The type-level 18.
+pub type N18 = There<N17>;
+/// The type-level 19.
+pub type N19 = There<N18>;
+/// The type-level 20.
+pub type N20 = There<N19>;
+/// The type-level 21.
+pub type N21 = There<N20>;
+/// The type-level 22.
+pub type N22 = There<N21>;
+/// The type-level 23.
+pub type N23 = There<N22>;
+/// The type-level 24.
+pub type N24 = There<N23>;
+/// The type-level 25.
+pub type N25 = There<N24>;
+/// The type-level 26.
+pub type N26 = There<N25>;
+/// The type-level 27.
+pub type N27 = There<N26>;
+/// The type-level 28.
+pub type N28 = There<N27>;
+/// The type-level 29.
+pub type N29 = There<N28>;
+/// The type-level 30.
+pub type N30 = There<N29>;
+/// The type-level 31.
+pub type N31 = There<N30>;
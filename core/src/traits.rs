@@ -44,6 +44,27 @@ pub trait IntoReverse {
     fn into_reverse(self) -> Self::Output;
 }
 
+/// Trait for getting the names of the types that make up a data structure,
+/// without needing a value of it.
+///
+/// Implemented for HLists (one name per element) and Coproducts (one name per
+/// variant). The names are those given by `std::any::type_name`, so the same
+/// caveats apply: they are meant for diagnostics, and their exact contents may
+/// change between compiler versions.
+///
+/// This functionality is also provided as an inherent static method [on HLists]
+/// and [on Coproducts]. However, you may find this trait useful in generic contexts.
+///
+/// [on HLists]: ../hlist/struct.HCons.html#method.type_names
+/// [on Coproducts]: ../coproduct/enum.Coproduct.html#method.type_names
+pub trait TypeNames {
+    /// An HList of `&'static str`, with the same length as `Self`.
+    type Names;
+
+    /// Returns the names of the types, in order.
+    fn type_names() -> Self::Names;
+}
+
 /// Wrapper type around a function for polymorphic maps and folds.
 ///
 /// This is a thin generic wrapper type that is used to differentiate
@@ -71,6 +92,155 @@ pub trait Func<Input> {
     /// cannot effectively close over a context. This decision trades power for convenience;
     /// a three-trait `Fn` heirarchy like that in std provides a great deal of power in a
     /// small fraction of use-cases, but it also comes at great expanse to the other 95% of
-    /// use cases.
+    /// use cases. When a context is needed, see [`FuncRef`] or [`FuncMut`] instead.
+    ///
+    /// [`FuncRef`]: trait.FuncRef.html
+    /// [`FuncMut`]: trait.FuncMut.html
     fn call(i: Input) -> Self::Output;
 }
+
+/// Wrapper type around a stateful function for polymorphic maps and folds.
+///
+/// This is the counterpart of [`Poly`] for types implementing [`FuncMut`].
+/// Because [`FuncMut::call_mut`] takes `&mut self`, the wrapped value can carry
+/// context (a counter, a formatter, some configuration...) that is shared by
+/// all of the calls made during a single map or fold.
+///
+/// To get the state back afterwards, wrap a mutable reference instead of the
+/// value itself; `&mut P` implements `FuncMut` whenever `P` does.
+///
+/// ```
+/// # #[macro_use] extern crate frunk;
+/// # fn main() {
+/// use frunk::{FuncMut, PolyMut};
+///
+/// struct Counter(usize);
+///
+/// impl FuncMut<i32> for Counter {
+///     type Output = (usize, i32);
+///     fn call_mut(&mut self, i: i32) -> Self::Output {
+///         self.0 += 1;
+///         (self.0, i)
+///     }
+/// }
+/// impl FuncMut<bool> for Counter {
+///     type Output = (usize, bool);
+///     fn call_mut(&mut self, b: bool) -> Self::Output {
+///         self.0 += 1;
+///         (self.0, b)
+///     }
+/// }
+///
+/// let mut counter = Counter(0);
+/// let numbered = hlist![7, true, 9].map(PolyMut(&mut counter));
+///
+/// assert_eq!(numbered, hlist![(1, 7), (2, true), (3, 9)]);
+/// assert_eq!(counter.0, 3);
+/// # }
+/// ```
+///
+/// [`Poly`]: struct.Poly.html
+/// [`FuncMut`]: trait.FuncMut.html
+/// [`FuncMut::call_mut`]: trait.FuncMut.html#tymethod.call_mut
+#[derive(Debug, Copy, Clone, Default)]
+pub struct PolyMut<T>(pub T);
+
+/// A user-implementable alternative to `FnMut`.
+///
+/// Unlike [`Func`], this takes a `self` argument, so implementors can close
+/// over (and update) some context. Use it through the [`PolyMut`] wrapper.
+///
+/// [`Func`]: trait.Func.html
+/// [`PolyMut`]: struct.PolyMut.html
+pub trait FuncMut<Input> {
+    type Output;
+
+    /// Call the `FuncMut`.
+    fn call_mut(&mut self, i: Input) -> Self::Output;
+}
+
+impl<P, Input> FuncMut<Input> for &mut P
+where
+    P: FuncMut<Input> + ?Sized,
+{
+    type Output = <P as FuncMut<Input>>::Output;
+
+    #[inline(always)]
+    fn call_mut(&mut self, i: Input) -> Self::Output {
+        (**self).call_mut(i)
+    }
+}
+
+/// Wrapper type around a function with read-only context for polymorphic
+/// maps and folds.
+///
+/// This is the counterpart of [`Poly`] for types implementing [`FuncRef`].
+/// Because [`FuncRef::call_ref`] only takes `&self`, the context (some
+/// configuration, a lookup table...) can be borrowed while it is shared
+/// elsewhere, which [`PolyMut`] does not allow.
+///
+/// `&P` implements `FuncRef` whenever `P` does, so the wrapped value can also
+/// be a reference.
+///
+/// ```
+/// # #[macro_use] extern crate frunk;
+/// # fn main() {
+/// use frunk::{FuncRef, PolyRef};
+///
+/// struct Scale(i32);
+///
+/// impl FuncRef<i32> for Scale {
+///     type Output = i32;
+///     fn call_ref(&self, i: i32) -> Self::Output {
+///         i * self.0
+///     }
+/// }
+/// impl FuncRef<f32> for Scale {
+///     type Output = f32;
+///     fn call_ref(&self, f: f32) -> Self::Output {
+///         f * self.0 as f32
+///     }
+/// }
+///
+/// let scale = Scale(3);
+/// let shared = &scale;
+/// let scaled = hlist![1, 0.5f32].map(PolyRef(&scale));
+///
+/// assert_eq!(scaled, hlist![3, 1.5]);
+/// assert_eq!(shared.0, 3);
+/// # }
+/// ```
+///
+/// [`Poly`]: struct.Poly.html
+/// [`PolyMut`]: struct.PolyMut.html
+/// [`FuncRef`]: trait.FuncRef.html
+/// [`FuncRef::call_ref`]: trait.FuncRef.html#tymethod.call_ref
+#[derive(Debug, Copy, Clone, Default)]
+pub struct PolyRef<T>(pub T);
+
+/// A user-implementable alternative to `Fn`.
+///
+/// Unlike [`Func`], this takes a `self` argument, so implementors can close
+/// over some context, which they can read but not update. Use it through the
+/// [`PolyRef`] wrapper.
+///
+/// [`Func`]: trait.Func.html
+/// [`PolyRef`]: struct.PolyRef.html
+pub trait FuncRef<Input> {
+    type Output;
+
+    /// Call the `FuncRef`.
+    fn call_ref(&self, i: Input) -> Self::Output;
+}
+
+impl<P, Input> FuncRef<Input> for &P
+where
+    P: FuncRef<Input> + ?Sized,
+{
+    type Output = <P as FuncRef<Input>>::Output;
+
+    #[inline(always)]
+    fn call_ref(&self, i: Input) -> Self::Output {
+        (**self).call_ref(i)
+    }
+}
@@ -4,8 +4,7 @@ use quote::ToTokens;
 use syn::Data;
 
 /// Given an AST, returns an implementation of Generic using HList
-///
-/// Only works with Structs and Tuple Structs
+/// for Structs and Tuple Structs, or Coproduct (of HLists) for Enums
 pub fn impl_generic(input: TokenStream) -> impl ToTokens {
     let ast = to_ast(input);
     let name = &ast.ident;
@@ -38,7 +37,55 @@ pub fn impl_generic(input: TokenStream) -> impl ToTokens {
                 }
             }
         }
-        _ => panic!("Only Structs are supported. Enums/Unions cannot be turned into Generics."),
+        Data::Enum(ref data) => {
+            let variant_count = data.variants.len();
+            let mut repr_types = Vec::with_capacity(variant_count);
+            let mut into_arms = Vec::with_capacity(variant_count);
+            let mut from_arms = Vec::with_capacity(variant_count);
+
+            for (index, variant) in data.variants.iter().enumerate() {
+                let variant_name = &variant.ident;
+                let field_bindings = FieldBindings::new(&variant.fields);
+                let type_constr = field_bindings.build_type_constr(FieldBinding::build);
+                let coprod_constr = build_coprod_constr(
+                    index,
+                    field_bindings.build_hlist_constr(FieldBinding::build),
+                );
+
+                repr_types.push(field_bindings.build_hlist_type(FieldBinding::build_type));
+                into_arms.push(quote! {
+                    #name::#variant_name #type_constr => #coprod_constr
+                });
+                from_arms.push(quote! {
+                    #coprod_constr => #name::#variant_name #type_constr
+                });
+            }
+
+            let repr_type = build_coprod_type(repr_types);
+            let unreachable_arm = build_coprod_unreachable_arm(variant_count);
+
+            quote! {
+                #[allow(non_snake_case, non_camel_case_types)]
+                impl #impl_generics ::frunk_core::generic::Generic for #name #ty_generics #where_clause {
+
+                    type Repr = #repr_type;
+
+                    fn into(self) -> Self::Repr {
+                        match self {
+                            #(#into_arms,)*
+                        }
+                    }
+
+                    fn from(r: Self::Repr) -> Self {
+                        match r {
+                            #(#from_arms,)*
+                            #unreachable_arm
+                        }
+                    }
+                }
+            }
+        }
+        _ => panic!("Only Structs and Enums are supported. Unions cannot be turned into Generics."),
     };
 
     //     print!("{}", tree);
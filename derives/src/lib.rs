@@ -27,6 +27,9 @@ use quote::ToTokens;
 
 /// Derives a Generic instance based on HList for
 /// a given Struct or Tuple Struct
+///
+/// Enums are supported too, with a Coproduct of the HLists
+/// for each of their variants as representation
 #[proc_macro_derive(Generic)]
 pub fn generic(input: TokenStream) -> TokenStream {
     // Build the impl
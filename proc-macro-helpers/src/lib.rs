@@ -75,6 +75,42 @@ where
     result
 }
 
+/// Given a list of types, creates an AST for the corresponding Coproduct
+/// type.
+pub fn build_coprod_type<L: IntoIterator>(items: L) -> TokenStream2
+where
+    L::Item: ToTokens,
+    L::IntoIter: DoubleEndedIterator,
+{
+    let mut result = quote! { ::frunk_core::coproduct::CNil };
+    for item in items.into_iter().rev() {
+        result = quote! { ::frunk_core::coproduct::Coproduct<#item, #result> }
+    }
+    result
+}
+
+/// Given the position of a variant and an expression or pattern for its value,
+/// creates an AST for the corresponding Coproduct constructor, which may itself
+/// be used as an expression or a pattern.
+pub fn build_coprod_constr<T: ToTokens>(index: usize, item: T) -> TokenStream2 {
+    let mut result = quote! { ::frunk_core::coproduct::Coproduct::Inl(#item) };
+    for _ in 0..index {
+        result = quote! { ::frunk_core::coproduct::Coproduct::Inr(#result) }
+    }
+    result
+}
+
+/// Given the number of variants of a Coproduct, creates an AST for a match arm
+/// covering its (uninhabited) `CNil` terminator, as needed to make a match on
+/// the Coproduct exhaustive.
+pub fn build_coprod_unreachable_arm(variant_count: usize) -> TokenStream2 {
+    let mut pat = quote! { _frunk_cnil_ };
+    for _ in 0..variant_count {
+        pat = quote! { ::frunk_core::coproduct::Coproduct::Inr(#pat) }
+    }
+    quote! { #pat => match _frunk_cnil_ {} }
+}
+
 /// Given an Ident returns an AST for its type level representation based on the
 /// enums generated in frunk_core::labelled.
 ///
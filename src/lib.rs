@@ -251,7 +251,11 @@ pub use traits::Func;
 #[doc(no_inline)]
 pub use traits::Poly;
 #[doc(no_inline)]
+pub use traits::{FuncMut, FuncRef, PolyMut, PolyRef};
+#[doc(no_inline)]
 pub use traits::{ToMut, ToRef}; // useful for where bounds
+#[doc(no_inline)]
+pub use traits::TypeNames;
 
 #[doc(no_inline)]
 pub use coproduct::Coproduct;
@@ -296,6 +300,8 @@ pub mod prelude {
     //! The intent here is that `use frunk::prelude::*` is enough to provide
     //! access to any missing methods advertised in frunk's documentation.
 
+    #[doc(no_inline)]
+    pub use coproduct::ResultExt;
     #[doc(no_inline)]
     pub use hlist::HList; // for LEN
     #[doc(no_inline)]
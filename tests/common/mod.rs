@@ -56,6 +56,25 @@ pub struct SuperLongField {
 #[derive(Generic, Debug, PartialEq)]
 pub struct TupleStruct<'a>(pub &'a str, pub i32);
 
+#[derive(Generic, Debug, PartialEq, Clone)]
+pub enum ApiShape<'a> {
+    Circle { radius: f64 },
+    Rect(f64, f64),
+    Named(&'a str),
+    Empty,
+}
+
+#[derive(Generic, Debug, PartialEq)]
+pub enum DomainShape<'a> {
+    Round { r: f64 },
+    Rectangle(f64, f64),
+    Label(&'a str),
+    Nothing,
+}
+
+#[derive(Generic)]
+pub enum Never {}
+
 #[derive(LabelledGeneric)]
 pub struct NormalUser<'a> {
     pub first_name: &'a str,
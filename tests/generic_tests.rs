@@ -2,7 +2,8 @@ extern crate frunk;
 #[macro_use] // for the hlist macro
 extern crate frunk_core;
 
-use frunk::{convert_from, from_generic, into_generic};
+use frunk::coproduct::CNil;
+use frunk::{convert_from, from_generic, into_generic, Generic, HNil};
 
 mod common;
 use common::*;
@@ -70,6 +71,65 @@ fn test_struct_conversion_round_trip() {
     assert_eq!(a_again, before)
 }
 
+#[test]
+fn test_enum_into_generic() {
+    type Repr<'a> = Coprod!(Hlist![f64], Hlist![f64, f64], Hlist![&'a str], HNil);
+
+    let g = into_generic(ApiShape::Circle { radius: 1.5 });
+    assert_eq!(g, Repr::inject(hlist![1.5]));
+
+    let g = into_generic(ApiShape::Rect(2.0, 3.0));
+    assert_eq!(g, Repr::inject(hlist![2.0, 3.0]));
+
+    let g = into_generic(ApiShape::Named("blob"));
+    assert_eq!(g, Repr::inject(hlist!["blob"]));
+
+    let g = into_generic(ApiShape::Empty);
+    assert_eq!(g, Repr::inject(HNil));
+}
+
+#[test]
+fn test_enum_from_generic() {
+    type Repr<'a> = <ApiShape<'a> as Generic>::Repr;
+
+    let s: ApiShape = from_generic(Repr::inject(hlist![4.0, 5.0]));
+    assert_eq!(s, ApiShape::Rect(4.0, 5.0));
+
+    let s: ApiShape = from_generic(Repr::inject(HNil));
+    assert_eq!(s, ApiShape::Empty);
+}
+
+#[test]
+fn test_enum_conversion_round_trip() {
+    let shapes = vec![
+        ApiShape::Circle { radius: 1.0 },
+        ApiShape::Rect(1.0, 2.0),
+        ApiShape::Named("blob"),
+        ApiShape::Empty,
+    ];
+    let expected = vec![
+        DomainShape::Round { r: 1.0 },
+        DomainShape::Rectangle(1.0, 2.0),
+        DomainShape::Label("blob"),
+        DomainShape::Nothing,
+    ];
+    for (shape, expected) in shapes.into_iter().zip(expected) {
+        let before = shape.clone();
+        let domain: DomainShape = convert_from(shape);
+        assert_eq!(domain, expected);
+        let api_again: ApiShape = convert_from(domain);
+        assert_eq!(api_again, before);
+    }
+}
+
+#[test]
+fn test_empty_enum_generic() {
+    fn repr_of_never(r: <Never as Generic>::Repr) -> CNil {
+        r
+    }
+    let _ = repr_of_never;
+}
+
 #[test]
 fn test_mixed_conversions_round_trip() {
     // Both SavedUser and ApiUser derive both Generic and LabelledGeneric